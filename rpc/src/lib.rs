@@ -0,0 +1,201 @@
+//! RPC interface for the call RBAC pallet.
+//!
+//! Calls and origins are passed as SCALE encoded bytes.
+use codec::{Codec, Decode, Encode};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+pub use pallet_call_rbac_runtime_api::CallRbacApi as CallRbacRuntimeApi;
+use pallet_call_rbac_runtime_api::{Role, RoleDetails, RoleId};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, sync::Arc};
+
+#[rpc(client, server)]
+pub trait CallRbacApi<BlockHash, AccountId> {
+    /// Return (id, encoded call, encoded dispatch origin) for each exact call allowed for who
+    #[method(name = "callRbac_allowedCalls")]
+    fn allowed_calls(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(RoleId, Bytes, Bytes)>>;
+
+    /// Return (account, role) for each member of id
+    #[method(name = "callRbac_roleMembers")]
+    fn role_members(&self, id: RoleId, at: Option<BlockHash>) -> RpcResult<Vec<(AccountId, Role)>>;
+
+    /// Return (id, role) for each role of who
+    #[method(name = "callRbac_accountRoles")]
+    fn account_roles(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(RoleId, Role)>>;
+
+    /// Return (id, encoded dispatch origin) iff who may execute the encoded call
+    #[method(name = "callRbac_canExecute")]
+    fn can_execute(
+        &self,
+        who: AccountId,
+        call: Bytes,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<(RoleId, Bytes)>>;
+
+    /// Return owner, metadata, members and (encoded call, encoded dispatch origin)
+    /// for each exact call of id
+    #[method(name = "callRbac_roleDetails")]
+    fn role_details(
+        &self,
+        id: RoleId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<RoleDetails<AccountId, Bytes, Bytes>>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+    /// The call could not be decoded.
+    DecodeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::DecodeError => 2,
+        }
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> CallError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query call RBAC permissions.",
+        Some(format!("{:?}", e)),
+    ))
+}
+
+/// Provides RPC methods to query call RBAC permissions.
+pub struct CallRbac<C, Block, RuntimeCall, PalletsOrigin> {
+    client: Arc<C>,
+    _marker: PhantomData<(Block, RuntimeCall, PalletsOrigin)>,
+}
+
+impl<C, Block, RuntimeCall, PalletsOrigin> CallRbac<C, Block, RuntimeCall, PalletsOrigin> {
+    /// Creates a new instance of the CallRbac Rpc helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, RuntimeCall, PalletsOrigin>
+    CallRbacApiServer<<Block as BlockT>::Hash, AccountId>
+    for CallRbac<C, Block, RuntimeCall, PalletsOrigin>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: CallRbacRuntimeApi<Block, AccountId, RuntimeCall, PalletsOrigin>,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    RuntimeCall: Codec + Send + Sync + 'static,
+    PalletsOrigin: Codec + Send + Sync + 'static,
+{
+    fn allowed_calls(
+        &self,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(RoleId, Bytes, Bytes)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let calls = self
+            .client
+            .runtime_api()
+            .allowed_calls(at, who)
+            .map_err(runtime_error)?;
+        Ok(calls
+            .into_iter()
+            .map(|(id, call, origin)| (id, call.encode().into(), origin.encode().into()))
+            .collect())
+    }
+
+    fn role_members(
+        &self,
+        id: RoleId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(AccountId, Role)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        Ok(self
+            .client
+            .runtime_api()
+            .role_members(at, id)
+            .map_err(runtime_error)?)
+    }
+
+    fn account_roles(
+        &self,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(RoleId, Role)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        Ok(self
+            .client
+            .runtime_api()
+            .account_roles(at, who)
+            .map_err(runtime_error)?)
+    }
+
+    fn can_execute(
+        &self,
+        who: AccountId,
+        call: Bytes,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<(RoleId, Bytes)>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let call = RuntimeCall::decode(&mut &*call).map_err(|e| {
+            CallError::Custom(ErrorObject::owned(
+                Error::DecodeError.into(),
+                "Unable to decode call.",
+                Some(format!("{:?}", e)),
+            ))
+        })?;
+        let permitted = self
+            .client
+            .runtime_api()
+            .can_execute(at, who, call)
+            .map_err(runtime_error)?;
+        Ok(permitted.map(|(id, origin)| (id, origin.encode().into())))
+    }
+
+    fn role_details(
+        &self,
+        id: RoleId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<RoleDetails<AccountId, Bytes, Bytes>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let details = self
+            .client
+            .runtime_api()
+            .role_details(at, id)
+            .map_err(runtime_error)?;
+        Ok(details.map(|details| RoleDetails {
+            owner: details.owner,
+            opaque_metadata: details.opaque_metadata,
+            metadata: details.metadata,
+            members: details.members,
+            calls: details
+                .calls
+                .into_iter()
+                .map(|(call, origin)| (call.encode().into(), origin.encode().into()))
+                .collect(),
+        }))
+    }
+}
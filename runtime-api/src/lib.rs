@@ -0,0 +1,26 @@
+//! Runtime API definition for the call RBAC pallet.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_call_rbac::{Role, RoleDetails, RoleId};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Query permissions of the call RBAC pallet
+    pub trait CallRbacApi<AccountId, RuntimeCall, PalletsOrigin> where
+        AccountId: Codec,
+        RuntimeCall: Codec,
+        PalletsOrigin: Codec,
+    {
+        /// Return (id, call, dispatch origin) for each exact call allowed for who
+        fn allowed_calls(who: AccountId) -> Vec<(RoleId, RuntimeCall, PalletsOrigin)>;
+        /// Return (account, role) for each member of id
+        fn role_members(id: RoleId) -> Vec<(AccountId, Role)>;
+        /// Return (id, role) for each role of who
+        fn account_roles(who: AccountId) -> Vec<(RoleId, Role)>;
+        /// Return (id, dispatch origin) iff who may execute call
+        fn can_execute(who: AccountId, call: RuntimeCall) -> Option<(RoleId, PalletsOrigin)>;
+        /// Return owner, metadata, members and exact calls of id
+        fn role_details(id: RoleId) -> Option<RoleDetails<AccountId, RuntimeCall, PalletsOrigin>>;
+    }
+}
@@ -0,0 +1,708 @@
+//! Call-RBAC benchmarks.
+use super::*;
+use crate::Pallet as CallRBAC;
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Hash, Saturating};
+use sp_std::{boxed::Box, vec, vec::Vec};
+
+/// Distinct call for each input
+fn remark<T: Config<I>, I: 'static>(i: u32) -> <T as Config<I>>::RuntimeCall {
+    frame_system::Call::<T>::remark { remark: i.encode() }.into()
+}
+
+fn remark_and_origin<T: Config<I>, I: 'static>(i: u32, from: &T::AccountId) -> CallAndOrigin<T, I> {
+    CallOrigin {
+        call: remark::<T, I>(i),
+        origin: RawOrigin::Signed(from.clone()).into(),
+    }
+}
+
+/// Add `n` distinct calls for id, starting with the call for `start`,
+/// holding their deposits from `from`
+fn add_calls<T: Config<I>, I: 'static>(id: RoleId, start: u32, n: u32, from: &T::AccountId) {
+    let calls = (start..start.saturating_add(n))
+        .map(|i| {
+            let CallOrigin { call, origin } = remark_and_origin::<T, I>(i, from);
+            (CallRBAC::<T, I>::encode_call(&call).unwrap(), origin)
+        })
+        .collect::<Vec<_>>();
+    fund::<T, I>(from);
+    CallRBAC::<T, I>::do_add_calls(id, calls, Some(from)).unwrap();
+}
+
+/// Add `MaxCalls` constraints which do not match remarks for id
+fn add_constraints<T: Config<I>, I: 'static>(id: RoleId, from: &T::AccountId) {
+    for i in 0..T::MaxCalls::get() {
+        CallConstraints::<T, I>::insert(
+            id,
+            T::BenchmarkHelper::call_constraint(i),
+            <T as Config<I>>::PalletsOrigin::from(RawOrigin::Signed(from.clone())),
+        );
+    }
+}
+
+/// Add `MaxCalls` constraints for id of which only the last in storage order matches the
+/// returned call, so that finding the call scans every constraint
+fn add_constraints_matching_last<T: Config<I>, I: 'static>(
+    id: RoleId,
+    from: &T::AccountId,
+) -> <T as Config<I>>::RuntimeCall {
+    let (constraint, call) = T::BenchmarkHelper::matching_call_constraint();
+    let last = CallConstraints::<T, I>::hashed_key_for(id, &constraint);
+    let origin = <T as Config<I>>::PalletsOrigin::from(RawOrigin::Signed(from.clone()));
+    (0..T::MaxCalls::get().saturating_mul(64))
+        .map(T::BenchmarkHelper::call_constraint)
+        .filter(|other| CallConstraints::<T, I>::hashed_key_for(id, other) < last)
+        .take(T::MaxCalls::get().saturating_sub(1) as usize)
+        .for_each(|other| CallConstraints::<T, I>::insert(id, other, origin.clone()));
+    CallConstraints::<T, I>::insert(id, constraint, origin);
+    call
+}
+
+/// Create roles until id exists
+fn create_roles<T: Config<I>, I: 'static>(id: RoleId) {
+    while NextRoleId::<T, I>::get() <= id {
+        CallRBAC::<T, I>::do_create_role(account("owner", 0, 0), Default::default());
+    }
+}
+
+/// Create id and grant admin access to caller for id
+fn admin<T: Config<I>, I: 'static>(id: RoleId) -> T::AccountId {
+    create_roles::<T, I>(id);
+    let caller: T::AccountId = whitelisted_caller();
+    CallRBAC::<T, I>::do_grant(id, &caller, Role::Admin, None).unwrap();
+    caller
+}
+
+/// Grant executer access to who for `n` ids other than the input id
+fn fill_roles<T: Config<I>, I: 'static>(id: RoleId, who: &T::AccountId, n: u32) {
+    for other in (0..n).map(|i| id.saturating_add(1).saturating_add(i as RoleId)) {
+        CallRBAC::<T, I>::do_grant(other, who, Role::Executer, None).unwrap();
+    }
+}
+
+/// Set quotas on both the executer and the call for id
+fn set_quotas<T: Config<I>, I: 'static>(
+    id: RoleId,
+    who: &T::AccountId,
+    call: &<T as Config<I>>::RuntimeCall,
+) {
+    let quota = Quota {
+        per_period: Some((u32::MAX, 1u32.into())),
+        total: Some(u32::MAX),
+    };
+    Quotas::<T, I>::insert(id, QuotaTarget::Executer(who.clone()), quota);
+    Quotas::<T, I>::insert(id, QuotaTarget::Call(T::Hashing::hash_of(call)), quota);
+}
+
+/// Fill the audit log of id so that recording an action prunes the oldest entry
+fn fill_audit_log<T: Config<I>, I: 'static>(id: RoleId) {
+    for _ in 0..T::AuditLogDepth::get() {
+        CallRBAC::<T, I>::audit(id, None, AuditAction::CallsChanged);
+    }
+}
+
+/// Fund who to pay deposits
+fn fund<T: Config<I>, I: 'static>(who: &T::AccountId) {
+    let _ = T::Currency::set_balance(who, BalanceOf::<T, I>::max_value() / 4u32.into());
+}
+
+/// Fund the account paying deposits for changes made by origin
+fn fund_payer<T: Config<I>, I: 'static>(origin: &<T as frame_system::Config>::RuntimeOrigin) {
+    let actor = frame_system::ensure_signed(origin.clone()).ok();
+    fund::<T, I>(&CallRBAC::<T, I>::deposit_payer(actor));
+}
+
+/// Fund the id account and set a fee policy which transfers from it
+fn fund_refunds<T: Config<I>, I: 'static>(id: RoleId) {
+    let _ = T::Currency::set_balance(
+        &CallRBAC::<T, I>::role_account(id),
+        BalanceOf::<T, I>::max_value() / 4u32.into(),
+    );
+    FeePolicies::<T, I>::insert(id, FeePolicy::Refund);
+}
+
+/// Metadata of the max name, description and tags lengths
+fn max_metadata<T: Config<I>, I: 'static>() -> RoleMetadataOf<T, I> {
+    let tag: BoundedVec<u8, T::MaxTagLen> =
+        vec![0u8; T::MaxTagLen::get() as usize].try_into().unwrap();
+    RoleMetadata {
+        name: vec![0u8; T::MaxNameLen::get() as usize].try_into().unwrap(),
+        description: vec![0u8; T::MaxDescriptionLen::get() as usize]
+            .try_into()
+            .unwrap(),
+        tags: vec![tag; T::MaxTags::get() as usize].try_into().unwrap(),
+    }
+}
+
+#[instance_benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn grant_access() {
+        let caller = admin::<T, I>(0);
+        fund::<T, I>(&caller);
+        let who: T::AccountId = account("who", 0, 0);
+        fill_roles::<T, I>(0, &who, T::MaxRolesPerAccount::get().saturating_sub(1));
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, who.clone(), Role::Executer);
+
+        assert_eq!(CallRBAC::<T, I>::role(0, &who), Some(Role::Executer));
+    }
+
+    #[benchmark]
+    fn revoke_access() {
+        let caller = admin::<T, I>(0);
+        let who: T::AccountId = account("who", 0, 0);
+        CallRBAC::<T, I>::do_grant(0, &who, Role::Executer, None).unwrap();
+        fund::<T, I>(&caller);
+        CallRBAC::<T, I>::hold_grant_deposit(0, &who, caller.clone()).unwrap();
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, who.clone());
+
+        assert!(Roles::<T, I>::get(0, &who).is_none());
+    }
+
+    #[benchmark]
+    fn set_calls(x: Linear<0, { T::MaxCalls::get() }>) -> Result<(), BenchmarkError> {
+        create_roles::<T, I>(0);
+        let origin =
+            T::SuperUser::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let from: T::AccountId = account("from", 0, 0);
+        add_calls::<T, I>(0, T::MaxCalls::get(), T::MaxCalls::get(), &from);
+        fund_payer::<T, I>(&origin);
+        let calls = (0..x)
+            .map(|i| remark_and_origin::<T, I>(i, &from))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            0,
+            calls,
+        );
+
+        assert_eq!(CallCount::<T, I>::get(0), x);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_call_constraints(x: Linear<0, { T::MaxCalls::get() }>) -> Result<(), BenchmarkError> {
+        create_roles::<T, I>(0);
+        let origin =
+            T::SuperUser::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let from: T::AccountId = account("from", 0, 0);
+        add_constraints::<T, I>(0, &from);
+        let constraints = (0..x)
+            .map(|i| CallOrigin {
+                call: T::BenchmarkHelper::call_constraint(i),
+                origin: RawOrigin::Signed(from.clone()).into(),
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            0,
+            constraints,
+        );
+
+        assert_eq!(CallConstraints::<T, I>::iter_prefix(0).count() as u32, x);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_call_indices(x: Linear<0, { T::MaxCalls::get() }>) -> Result<(), BenchmarkError> {
+        create_roles::<T, I>(0);
+        let origin =
+            T::SuperUser::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let from: T::AccountId = account("from", 0, 0);
+        let index = |i: u32| CallIndex::Call((i / 256) as u8, (i % 256) as u8);
+        for i in 0..T::MaxCalls::get() {
+            CallIndexOrigins::<T, I>::insert(
+                0,
+                index(i),
+                <T as Config<I>>::PalletsOrigin::from(RawOrigin::Signed(from.clone())),
+            );
+        }
+        let indices = (0..x)
+            .map(|i| CallOrigin {
+                call: index(i),
+                origin: RawOrigin::Signed(from.clone()).into(),
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            0,
+            indices,
+        );
+
+        assert_eq!(CallIndexOrigins::<T, I>::iter_prefix(0).count() as u32, x);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_calls(x: Linear<1, { T::MaxCalls::get() }>) -> Result<(), BenchmarkError> {
+        create_roles::<T, I>(0);
+        let origin =
+            T::SuperUser::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let from: T::AccountId = account("from", 0, 0);
+        add_calls::<T, I>(0, x, T::MaxCalls::get() - x, &from);
+        fund_payer::<T, I>(&origin);
+        let calls = (0..x)
+            .map(|i| remark_and_origin::<T, I>(i, &from))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            0,
+            calls,
+        );
+
+        assert_eq!(CallCount::<T, I>::get(0), T::MaxCalls::get());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_calls(x: Linear<1, { T::MaxCalls::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::SuperUser::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let from: T::AccountId = account("from", 0, 0);
+        add_calls::<T, I>(0, 0, T::MaxCalls::get(), &from);
+        let calls = (0..x)
+            .map(|i| remark::<T, I>(i))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            0,
+            calls,
+        );
+
+        assert_eq!(CallCount::<T, I>::get(0), T::MaxCalls::get() - x);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn execute_call(r: Linear<1, { T::MaxRolesPerAccount::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let from: T::AccountId = account("from", 0, 0);
+        for id in 0..r as RoleId {
+            CallRBAC::<T, I>::do_grant(id, &caller, Role::Executer, None).unwrap();
+        }
+        // only the id scanned last permits the call, by its last constraint
+        let permitted = (0..r as RoleId)
+            .max_by_key(|id| Permissions::<T, I>::hashed_key_for(&caller, id))
+            .unwrap();
+        for id in (0..r as RoleId).filter(|id| *id != permitted) {
+            add_calls::<T, I>(id, 1, T::MaxCalls::get(), &from);
+            add_constraints::<T, I>(id, &from);
+        }
+        let call = add_constraints_matching_last::<T, I>(permitted, &from);
+        fund_refunds::<T, I>(permitted);
+        set_quotas::<T, I>(permitted, &caller, &call);
+        fill_audit_log::<T, I>(permitted);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), Box::new(call));
+    }
+
+    #[benchmark]
+    fn execute_call_with_role() {
+        let caller: T::AccountId = whitelisted_caller();
+        let from: T::AccountId = account("from", 0, 0);
+        CallRBAC::<T, I>::do_grant(0, &caller, Role::Executer, None).unwrap();
+        let call = add_constraints_matching_last::<T, I>(0, &from);
+        fund_refunds::<T, I>(0);
+        set_quotas::<T, I>(0, &caller, &call);
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, Box::new(call));
+    }
+
+    #[benchmark]
+    fn fund_role() {
+        create_roles::<T, I>(0);
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = T::Currency::minimum_balance();
+        let _ = T::Currency::set_balance(&caller, amount.saturating_mul(10u32.into()));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, amount);
+
+        assert_eq!(
+            T::Currency::balance(&CallRBAC::<T, I>::role_account(0)),
+            amount
+        );
+    }
+
+    #[benchmark]
+    fn set_fee_policy() -> Result<(), BenchmarkError> {
+        create_roles::<T, I>(0);
+        let origin =
+            T::SuperUser::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            0,
+            FeePolicy::Refund,
+        );
+
+        assert_eq!(FeePolicies::<T, I>::get(0), FeePolicy::Refund);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn grant_access_until() {
+        let caller = admin::<T, I>(0);
+        fund::<T, I>(&caller);
+        let who: T::AccountId = account("who", 0, 0);
+        fill_roles::<T, I>(0, &who, T::MaxRolesPerAccount::get().saturating_sub(1));
+        let expiry = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            0,
+            who.clone(),
+            Role::Executer,
+            expiry,
+        );
+
+        assert!(ExpiryQueue::<T, I>::contains_key(expiry, (0, who)));
+    }
+
+    #[benchmark]
+    fn expire_grant() {
+        let who: T::AccountId = account("who", 0, 0);
+        let expiry = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+        CallRBAC::<T, I>::do_grant(0, &who, Role::Executer, Some(expiry)).unwrap();
+        fund::<T, I>(&who);
+        CallRBAC::<T, I>::hold_grant_deposit(0, &who, who.clone()).unwrap();
+        let weight =
+            T::WeightInfo::expire_grant().saturating_add(T::DbWeight::get().reads_writes(2, 2));
+        fill_audit_log::<T, I>(0);
+
+        #[block]
+        {
+            CallRBAC::<T, I>::expire_grants(expiry, weight);
+        }
+
+        assert!(Roles::<T, I>::get(0, &who).is_none());
+    }
+
+    #[benchmark]
+    fn set_quota() {
+        let caller = admin::<T, I>(0);
+        let target = QuotaTarget::Executer(account("who", 0, 0));
+        let quota = Quota {
+            per_period: Some((1, 1u32.into())),
+            total: Some(1),
+        };
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, target.clone(), Some(quota));
+
+        assert_eq!(Quotas::<T, I>::get(0, target), Some(quota));
+    }
+
+    #[benchmark]
+    fn reset_usage() {
+        let caller = admin::<T, I>(0);
+        let target = QuotaTarget::Executer(account("who", 0, 0));
+        Usages::<T, I>::mutate(0, &target, |usage| usage.total = 1);
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, target.clone());
+
+        assert_eq!(Usages::<T, I>::get(0, target).total, 0);
+    }
+
+    #[benchmark]
+    fn set_approval_required() -> Result<(), BenchmarkError> {
+        create_roles::<T, I>(0);
+        let origin =
+            T::SuperUser::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let hash = T::Hashing::hash_of(&remark::<T, I>(0));
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            0,
+            hash,
+            true,
+        );
+
+        assert!(ApprovalRequired::<T, I>::contains_key(0, hash));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn propose_call() {
+        let caller: T::AccountId = whitelisted_caller();
+        let from: T::AccountId = account("from", 0, 0);
+        CallRBAC::<T, I>::do_grant(0, &caller, Role::Executer, None).unwrap();
+        add_calls::<T, I>(0, 0, 1, &from);
+        let hash = T::Hashing::hash_of(&remark::<T, I>(0));
+        ApprovalRequired::<T, I>::insert(0, hash, ());
+        // an expired proposal with approvals is replaced
+        let approvals = T::ApprovalThreshold::get().saturating_sub(1);
+        for i in 0..approvals {
+            let approver: T::AccountId = account("approver", i, 0);
+            Approvals::<T, I>::insert((0, hash), approver, ());
+        }
+        Proposals::<T, I>::insert(
+            0,
+            hash,
+            Proposal {
+                proposer: account("proposer", 0, 0),
+                expiry: frame_system::Pallet::<T>::block_number(),
+                approvals,
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0, hash);
+
+        assert_eq!(
+            Proposals::<T, I>::get(0, hash).map(|proposal| proposal.proposer),
+            Some(caller)
+        );
+    }
+
+    #[benchmark]
+    fn approve_call() {
+        let caller = admin::<T, I>(0);
+        let from: T::AccountId = account("from", 0, 0);
+        add_calls::<T, I>(0, 0, 1, &from);
+        let hash = T::Hashing::hash_of(&remark::<T, I>(0));
+        ApprovalRequired::<T, I>::insert(0, hash, ());
+        // caller gives the final approval which dispatches the call
+        let approvals = T::ApprovalThreshold::get().saturating_sub(1);
+        for i in 0..approvals {
+            let approver: T::AccountId = account("approver", i, 0);
+            Approvals::<T, I>::insert((0, hash), approver, ());
+        }
+        let proposer: T::AccountId = account("proposer", 0, 0);
+        set_quotas::<T, I>(0, &proposer, &remark::<T, I>(0));
+        Proposals::<T, I>::insert(
+            0,
+            hash,
+            Proposal {
+                proposer,
+                expiry: frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::ProposalLifetime::get()),
+                approvals,
+            },
+        );
+        fill_audit_log::<T, I>(0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, hash, Weight::MAX);
+
+        assert!(!Proposals::<T, I>::contains_key(0, hash));
+    }
+
+    #[benchmark]
+    fn cancel_proposal() {
+        let caller = admin::<T, I>(0);
+        let hash = T::Hashing::hash_of(&remark::<T, I>(0));
+        Approvals::<T, I>::insert((0, hash), account::<T::AccountId>("approver", 0, 0), ());
+        Proposals::<T, I>::insert(
+            0,
+            hash,
+            Proposal {
+                proposer: account("proposer", 0, 0),
+                expiry: frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::ProposalLifetime::get()),
+                approvals: 1,
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, hash);
+
+        assert!(!Proposals::<T, I>::contains_key(0, hash));
+    }
+
+    #[benchmark]
+    fn cancel_pending_change() -> Result<(), BenchmarkError> {
+        let origin =
+            T::SuperUser::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let from: T::AccountId = account("from", 0, 0);
+        let calls = (0..T::MaxCalls::get())
+            .map(|i| remark_and_origin::<T, I>(i, &from))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        let change = Change::SetCalls(0, CallRBAC::<T, I>::encode_calls(calls).unwrap());
+        PendingChanges::<T, I>::insert(
+            0,
+            (frame_system::Pallet::<T>::block_number(), None, change),
+        );
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin, 0);
+
+        assert!(!PendingChanges::<T, I>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn pause_role() -> Result<(), BenchmarkError> {
+        let origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin, 0);
+
+        assert!(PausedRoles::<T, I>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unpause_role() -> Result<(), BenchmarkError> {
+        create_roles::<T, I>(0);
+        let origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        PausedRoles::<T, I>::insert(0, ());
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin, 0);
+
+        assert!(!PausedRoles::<T, I>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn pause_all() -> Result<(), BenchmarkError> {
+        let origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin);
+
+        assert!(PausedAll::<T, I>::get());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unpause_all() -> Result<(), BenchmarkError> {
+        let origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        PausedAll::<T, I>::put(true);
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin);
+
+        assert!(!PausedAll::<T, I>::get());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_role() -> Result<(), BenchmarkError> {
+        let origin =
+            T::SuperUser::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let metadata: BoundedVec<u8, T::MaxMetadataLen> =
+            vec![0u8; T::MaxMetadataLen::get() as usize]
+                .try_into()
+                .unwrap();
+        let id = NextRoleId::<T, I>::get();
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            owner,
+            metadata,
+        );
+
+        assert!(RoleInfos::<T, I>::contains_key(id));
+        Ok(())
+    }
+
+    // n - 1 grants are removed and the call finds nothing left, so it also removes
+    // the call constraints and call indices and refunds the role account
+    #[benchmark]
+    fn destroy_role(n: Linear<1, { T::MaxCalls::get() }>) {
+        let owner: T::AccountId = whitelisted_caller();
+        let id = CallRBAC::<T, I>::do_create_role(owner.clone(), Default::default());
+        let from: T::AccountId = account("from", 0, 0);
+        let expiry = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+        fund::<T, I>(&owner);
+        for i in 1..n {
+            let who: T::AccountId = account("who", i, 0);
+            CallRBAC::<T, I>::do_grant(id, &who, Role::Executer, Some(expiry)).unwrap();
+            CallRBAC::<T, I>::hold_grant_deposit(id, &who, owner.clone()).unwrap();
+        }
+        add_constraints::<T, I>(id, &from);
+        for i in 0..T::MaxCalls::get() {
+            CallIndexOrigins::<T, I>::insert(
+                id,
+                CallIndex::Call((i / 256) as u8, (i % 256) as u8),
+                <T as Config<I>>::PalletsOrigin::from(RawOrigin::Signed(from.clone())),
+            );
+        }
+        fund_refunds::<T, I>(id);
+        fill_audit_log::<T, I>(id);
+        Metadata::<T, I>::insert(id, max_metadata::<T, I>());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), id, n);
+
+        assert!(!RoleInfos::<T, I>::contains_key(id));
+    }
+
+    #[benchmark]
+    fn set_role_metadata() {
+        let owner: T::AccountId = whitelisted_caller();
+        let id = CallRBAC::<T, I>::do_create_role(owner.clone(), Default::default());
+        let metadata = max_metadata::<T, I>();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner),
+            id,
+            metadata.name,
+            metadata.description,
+            metadata.tags,
+        );
+
+        assert!(Metadata::<T, I>::contains_key(id));
+    }
+
+    impl_benchmark_test_suite!(CallRBAC, crate::tests::new_test_ext(), crate::tests::Test);
+}
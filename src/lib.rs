@@ -9,25 +9,186 @@
 //! For each access level (`RoleId`), there are 2 roles:
 //! 1. **Admin**: may add/remove accounts to the `Executor` role for the access level
 //! 2. **Executor**: may execute dispatchable calls accessible to the access level
+//!
+//! Each access level also has a derivative account which may be funded to pay the
+//! fees of its Executors, depending on the `FeePolicy` set for the access level.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
 use frame_support::pallet_prelude::*;
 pub use pallet::*;
 use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_std::vec::Vec;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod membership;
+pub mod migrations;
+pub mod origins;
 #[cfg(test)]
 mod tests;
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub const LOG_TARGET: &str = "runtime::call-rbac";
 
 /// Distinct roles that never overlap, but this can be circumvented by Admin.
 /// Any Admin can easily assign themselves as an Executer under a new AccountId
 /// controlled by them.
-#[derive(PartialEq, Eq, Copy, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+#[derive(
+    PartialEq,
+    Eq,
+    Copy,
+    Clone,
+    MaxEncodedLen,
+    Encode,
+    Decode,
+    TypeInfo,
+    RuntimeDebug,
+    Serialize,
+    Deserialize,
+)]
 pub enum Role {
     Executer,
     Admin,
 }
 
+/// Role granted to an account, optionally until the expiry block
+#[derive(PartialEq, Eq, Copy, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct Grant<BlockNumber> {
+    pub role: Role,
+    /// Grant is treated as absent from this block
+    pub expiry: Option<BlockNumber>,
+}
+
+/// Payer of the fees for calls dispatched by an Executer
+#[derive(
+    PartialEq, Eq, Copy, Clone, Default, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug,
+)]
+pub enum FeePolicy {
+    /// Executer pays the transaction fee
+    #[default]
+    Executer,
+    /// Transaction fee is waived and burned from the role account instead.
+    /// The Executer must still hold the fee, which is withdrawn before dispatch and refunded after.
+    Free,
+    /// Executer pays the transaction fee and is reimbursed from the role account
+    Refund,
+}
+
+/// Subject of an execution quota for an id
+#[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum QuotaTarget<AccountId, Hash> {
+    /// Executions of any call by the Executer
+    Executer(AccountId),
+    /// Executions of the call with the hash by any Executer
+    Call(Hash),
+}
+
+/// Limits on the number of executions
+#[derive(PartialEq, Eq, Copy, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct Quota<BlockNumber> {
+    /// Max executions per period of blocks, if any
+    pub per_period: Option<(u32, BlockNumber)>,
+    /// Max executions in total, if any
+    pub total: Option<u32>,
+}
+
+/// Executions counted against a quota
+#[derive(
+    PartialEq, Eq, Copy, Clone, Default, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug,
+)]
+pub struct Usage<BlockNumber> {
+    /// First block of the current period
+    pub period_start: BlockNumber,
+    /// Executions in the current period
+    pub in_period: u32,
+    /// Executions in total
+    pub total: u32,
+}
+
+/// Privileged action recorded in the audit log of an id
+#[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum AuditAction<AccountId, Hash> {
+    /// Account granted role
+    Granted(AccountId, Role),
+    /// Account role revoked
+    Revoked(AccountId, Role),
+    /// Account role expired
+    Expired(AccountId, Role),
+    /// Calls, call constraints or call indices changed
+    CallsChanged,
+    /// Fee policy, quota, usage or approval requirement changed
+    SettingsChanged,
+    /// Call with hash executed
+    Executed(Hash),
+}
+
+/// Audit log entry
+#[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct AuditEntry<AccountId, BlockNumber, Hash> {
+    /// Signer of the action, `None` for other origins and automatic actions
+    pub actor: Option<AccountId>,
+    pub block: BlockNumber,
+    pub action: AuditAction<AccountId, Hash>,
+}
+
+/// Access level registered with `create_role`
+#[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct RoleInfo<AccountId, Metadata> {
+    /// May destroy the role, besides the `SuperUser`
+    pub owner: AccountId,
+    /// Opaque metadata given to `create_role`, e.g. a link to a document.
+    /// Human readable labels are set separately with `set_role_metadata`.
+    pub metadata: Metadata,
+    /// True once `destroy_role` started removing the storage of the role
+    pub destroying: bool,
+}
+
+/// Human readable metadata of an access level set with `set_role_metadata`
+#[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RoleMetadata<Name, Description, Tags> {
+    pub name: Name,
+    pub description: Description,
+    pub tags: Tags,
+}
+
+/// Owner and metadata of an access level alongside its members and exact calls
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RoleDetails<AccountId, Call, Origin> {
+    pub owner: AccountId,
+    /// Opaque metadata given to `create_role`
+    pub opaque_metadata: Vec<u8>,
+    /// Human readable metadata set with `set_role_metadata`
+    pub metadata: Option<RoleMetadata<Vec<u8>, Vec<u8>, Vec<Vec<u8>>>>,
+    /// (account, role) for each member
+    pub members: Vec<(AccountId, Role)>,
+    /// (call, dispatch origin) for each exact call
+    pub calls: Vec<(Call, Origin)>,
+}
+
+/// Call awaiting approval before dispatch
+#[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct Proposal<AccountId, BlockNumber> {
+    pub proposer: AccountId,
+    /// Proposal can no longer be approved from this block
+    pub expiry: BlockNumber,
+    /// Number of distinct approvals
+    pub approvals: u32,
+}
+
+/// Calls identified by their indices in the runtime
+#[derive(PartialEq, Eq, Copy, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum CallIndex {
+    /// Any call to the pallet at the index
+    Pallet(u8),
+    /// Call at (pallet index, call index)
+    Call(u8, u8),
+}
+
 /// Call alongside its origin
 #[derive(PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo, RuntimeDebug)]
 pub struct CallOrigin<Call, Origin> {
@@ -35,68 +196,169 @@ pub struct CallOrigin<Call, Origin> {
     pub origin: Origin,
 }
 
-/// Return dispatch origin for call iff call is permitted for who
-pub trait ValidateCall<T: pallet::Config> {
+/// Return permitting id and dispatch origin for call iff call is permitted for who
+pub trait ValidateCall<T: pallet::Config<I>, I: 'static = ()> {
     fn validate_call(
         who: &T::AccountId,
-        call: &<T as Config>::RuntimeCall,
-    ) -> Option<<T as Config>::RuntimeOrigin>;
+        call: &<T as Config<I>>::RuntimeCall,
+    ) -> Option<(RoleId, <T as Config<I>>::RuntimeOrigin)>;
+    /// Return dispatch origin for call iff call is permitted for who as Executer of id
+    fn validate_call_with_role(
+        id: RoleId,
+        who: &T::AccountId,
+        call: &<T as Config<I>>::RuntimeCall,
+    ) -> Option<<T as Config<I>>::RuntimeOrigin>;
+}
+
+/// Match a call against a constraint on its variant or arguments,
+/// in the spirit of `InstanceFilter` in `pallet_proxy`
+pub trait MatchCall<Call> {
+    /// Return true iff call satisfies the constraint
+    fn matches(&self, call: &Call) -> bool;
+}
+
+impl<Call> MatchCall<Call> for () {
+    fn matches(&self, _: &Call) -> bool {
+        false
+    }
+}
+
+/// Create inputs for benchmarks that depend on the runtime
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<CallConstraint, Call> {
+    /// Return a distinct constraint for each input which does not match
+    /// `frame_system::Call::remark`
+    fn call_constraint(i: u32) -> CallConstraint;
+    /// Return a constraint and a call which it matches. The call must not be matched by
+    /// any constraint returned by `call_constraint`, nor be a `frame_system::Call::remark`
+    fn matching_call_constraint() -> (CallConstraint, Call);
 }
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
-    use frame_support::traits::{CallerTrait, OriginTrait};
+    use frame_support::dispatch::{
+        extract_actual_weight, DispatchClass, GetDispatchInfo, Pays, PostDispatchInfo,
+    };
+    use frame_support::storage::with_storage_layer;
+    use frame_support::traits::{
+        fungible::{Inspect, InspectHold, Mutate, MutateHold},
+        tokens::{Fortitude, Precision, Preservation},
+        CallerTrait, OriginTrait,
+    };
+    use frame_support::weights::WeightToFee;
+    use frame_support::PalletId;
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::Dispatchable;
-    use sp_std::vec::Vec;
+    use sp_runtime::traits::{AccountIdConversion, Dispatchable, Hash, One, Zero};
+    use sp_std::{boxed::Box, vec::Vec};
 
-    #[pallet::pallet]
-    #[pallet::without_storage_info]
-    pub struct Pallet<T>(_);
-
-    pub trait WeightInfo {
-        fn grant_access() -> Weight;
-        fn revoke_access() -> Weight;
-        fn set_calls(x: u32) -> Weight;
-        fn execute_call() -> Weight;
-    }
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
-    impl WeightInfo for () {
-        fn grant_access() -> Weight {
-            Weight::default()
-        }
-        fn revoke_access() -> Weight {
-            Weight::default()
-        }
-        fn set_calls(_: u32) -> Weight {
-            Weight::default()
-        }
-        fn execute_call() -> Weight {
-            Weight::default()
-        }
-    }
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
     pub type RoleId = u64;
     /// Call alongside its dispatch origin.
-    pub type CallAndOrigin<T> =
-        CallOrigin<<T as Config>::RuntimeCall, <T as Config>::PalletsOrigin>;
+    pub type CallAndOrigin<T, I = ()> =
+        CallOrigin<<T as Config<I>>::RuntimeCall, <T as Config<I>>::PalletsOrigin>;
+    /// Call index alongside the dispatch origin of calls at the index.
+    pub type CallIndexAndOrigin<T, I = ()> = CallOrigin<CallIndex, <T as Config<I>>::PalletsOrigin>;
+    /// Call constraint alongside the dispatch origin of matching calls.
+    pub type ConstraintAndOrigin<T, I = ()> =
+        CallOrigin<<T as Config<I>>::CallConstraint, <T as Config<I>>::PalletsOrigin>;
+    /// SCALE encoded call bounded by `MaxCallLen`.
+    pub type EncodedCallOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxCallLen>;
+    /// Hash of an encoded call.
+    pub type CallHashOf<T> = <T as frame_system::Config>::Hash;
+    pub type GrantOf<T> = Grant<BlockNumberFor<T>>;
+    pub type QuotaTargetOf<T> = QuotaTarget<<T as frame_system::Config>::AccountId, CallHashOf<T>>;
+    pub type QuotaOf<T> = Quota<BlockNumberFor<T>>;
+    pub type UsageOf<T> = Usage<BlockNumberFor<T>>;
+    pub type ProposalOf<T> = Proposal<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+    /// Encoded calls alongside their dispatch origins.
+    pub type EncodedCallsOf<T, I = ()> = BoundedVec<
+        (EncodedCallOf<T, I>, <T as Config<I>>::PalletsOrigin),
+        <T as Config<I>>::MaxCalls,
+    >;
+    pub type ChangeId = u32;
+    pub type RoleInfoOf<T, I = ()> = RoleInfo<
+        <T as frame_system::Config>::AccountId,
+        BoundedVec<u8, <T as Config<I>>::MaxMetadataLen>,
+    >;
+    /// Metadata bounded by `MaxNameLen`, `MaxDescriptionLen`, `MaxTags` and `MaxTagLen`.
+    pub type RoleMetadataOf<T, I = ()> = RoleMetadata<
+        BoundedVec<u8, <T as Config<I>>::MaxNameLen>,
+        BoundedVec<u8, <T as Config<I>>::MaxDescriptionLen>,
+        BoundedVec<BoundedVec<u8, <T as Config<I>>::MaxTagLen>, <T as Config<I>>::MaxTags>,
+    >;
+    pub type RoleDetailsOf<T, I = ()> = RoleDetails<
+        <T as frame_system::Config>::AccountId,
+        <T as Config<I>>::RuntimeCall,
+        <T as Config<I>>::PalletsOrigin,
+    >;
+    pub type AuditActionOf<T> = AuditAction<<T as frame_system::Config>::AccountId, CallHashOf<T>>;
+    pub type AuditEntryOf<T> =
+        AuditEntry<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, CallHashOf<T>>;
+    pub type BalanceOf<T, I = ()> =
+        <<T as Config<I>>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Permission or role change, applied after `ChangeDelay`
+    #[derive(
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        Encode,
+        Decode,
+        TypeInfo,
+        MaxEncodedLen,
+        RuntimeDebugNoBound,
+    )]
+    #[scale_info(skip_type_params(T, I))]
+    #[codec(mel_bound(T: Config<I>, I: 'static))]
+    pub enum Change<T: Config<I>, I: 'static = ()> {
+        /// Grant Id role to Account, optionally until the expiry block
+        Grant(RoleId, T::AccountId, Role, Option<BlockNumberFor<T>>),
+        /// Revoke Id role of Account
+        Revoke(RoleId, T::AccountId),
+        /// Replace Id calls
+        SetCalls(RoleId, EncodedCallsOf<T, I>),
+        /// Add Id calls
+        AddCalls(RoleId, EncodedCallsOf<T, I>),
+        /// Remove Id calls by hash
+        RemoveCalls(RoleId, BoundedVec<CallHashOf<T>, T::MaxCalls>),
+        /// Replace Id call constraints
+        SetCallConstraints(RoleId, BoundedVec<ConstraintAndOrigin<T, I>, T::MaxCalls>),
+        /// Replace Id call indices
+        SetCallIndices(RoleId, BoundedVec<CallIndexAndOrigin<T, I>, T::MaxCalls>),
+        /// Set Id fee policy
+        SetFeePolicy(RoleId, FeePolicy),
+        /// Set or remove Id quota of the target
+        SetQuota(RoleId, QuotaTargetOf<T>, Option<QuotaOf<T>>),
+        /// Reset Id usage of the target
+        ResetUsage(RoleId, QuotaTargetOf<T>),
+        /// Set whether Id call with hash requires approval
+        SetApprovalRequired(RoleId, CallHashOf<T>, bool),
+    }
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config<I: 'static = ()>: frame_system::Config {
         /// The overarching event type
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// Call weight information
         type WeightInfo: WeightInfo;
         /// The super user origin.
         type SuperUser: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+        /// Origin which may pause and unpause execution, besides the `SuperUser`
+        type PauseOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
         /// Validate the call executed through this pallet
-        type ValidateCall: ValidateCall<Self>;
+        type ValidateCall: ValidateCall<Self, I>;
         /// A dispatchable call.
         type RuntimeCall: Parameter
             + Dispatchable<
-                RuntimeOrigin = <Self as Config>::RuntimeOrigin,
+                RuntimeOrigin = <Self as Config<I>>::RuntimeOrigin,
                 PostInfo = PostDispatchInfo,
             > + GetDispatchInfo
             + From<frame_system::Call<Self>>;
@@ -111,21 +373,147 @@ pub mod pallet {
         /// The max number of calls for a single ID
         #[pallet::constant]
         type MaxCalls: Get<u32>;
+        /// The max number of ids for which a single account is Executer
+        #[pallet::constant]
+        type MaxRolesPerAccount: Get<u32>;
+        /// The max length of an encoded call
+        #[pallet::constant]
+        type MaxCallLen: Get<u32>;
+        /// Constraint matching calls by variant or arguments
+        type CallConstraint: Parameter + MaxEncodedLen + MatchCall<<Self as Config<I>>::RuntimeCall>;
+        /// Currency used to fund role accounts, pay Executer fees and hold deposits
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = <Self as Config<I>>::RuntimeHoldReason>;
+        /// The overarching hold reason
+        type RuntimeHoldReason: From<HoldReason<I>>;
+        /// Deposit held for each grant made through `grant_access` or `grant_access_until`
+        #[pallet::constant]
+        type GrantDeposit: Get<BalanceOf<Self, I>>;
+        /// Deposit held per byte of each encoded call added with `set_calls` or `add_calls`
+        #[pallet::constant]
+        type CallDepositPerByte: Get<BalanceOf<Self, I>>;
+        /// Account paying deposits for changes made by unsigned origins such as Root
+        type DepositPayer: Get<Self::AccountId>;
+        /// Converts the weight of an executed call into the fee paid by the role account
+        type WeightToFee: WeightToFee<Balance = BalanceOf<Self, I>>;
+        /// Number of distinct approvals which dispatch a proposed call
+        #[pallet::constant]
+        type ApprovalThreshold: Get<u32>;
+        /// Number of blocks a proposed call may be approved for
+        #[pallet::constant]
+        type ProposalLifetime: Get<BlockNumberFor<Self>>;
+        /// Number of blocks between announcing and applying permission and role changes.
+        /// Benchmarks must run with a zero delay so that weights cover applying changes.
+        #[pallet::constant]
+        type ChangeDelay: Get<BlockNumberFor<Self>>;
+        /// Number of most recent entries kept in the audit log of each id
+        #[pallet::constant]
+        type AuditLogDepth: Get<u32>;
+        /// The max length of role metadata
+        #[pallet::constant]
+        type MaxMetadataLen: Get<u32>;
+        /// The max length of the name in `set_role_metadata`
+        #[pallet::constant]
+        type MaxNameLen: Get<u32>;
+        /// The max length of the description in `set_role_metadata`
+        #[pallet::constant]
+        type MaxDescriptionLen: Get<u32>;
+        /// The max number of tags in `set_role_metadata`
+        #[pallet::constant]
+        type MaxTags: Get<u32>;
+        /// The max length of each tag in `set_role_metadata`
+        #[pallet::constant]
+        type MaxTagLen: Get<u32>;
+        /// Pallet identifier from which role accounts are derived
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+        /// Helper to create call constraints in benchmarks
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<
+            Self::CallConstraint,
+            <Self as Config<I>>::RuntimeCall,
+        >;
     }
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         /// Id granted Account access defined by Role
         AccessGranted(RoleId, T::AccountId, Role),
         /// Id revoked Account access defined by Role
         AccessRevoked(RoleId, T::AccountId, Role),
         /// Id granted access to calls
         CallsUpdated(RoleId),
+        /// Id granted access to calls matching constraints
+        CallConstraintsUpdated(RoleId),
+        /// Id granted access to calls with hashes
+        CallsAdded(RoleId, Vec<CallHashOf<T>>),
+        /// Id revoked access to calls with hashes
+        CallsRemoved(RoleId, Vec<CallHashOf<T>>),
+        /// Id granted access to calls by index
+        CallIndicesUpdated(RoleId),
+        /// Account funded the account of Id
+        RoleFunded(RoleId, T::AccountId, BalanceOf<T, I>),
+        /// Id fee policy updated
+        FeePolicyUpdated(RoleId, FeePolicy),
+        /// Id granted Account access defined by Role until the expiry block
+        AccessGrantedUntil(RoleId, T::AccountId, Role, BlockNumberFor<T>),
+        /// Id access of Account defined by Role expired
+        AccessExpired(RoleId, T::AccountId, Role),
+        /// Id quota for target updated
+        QuotaUpdated(RoleId, QuotaTargetOf<T>, Option<QuotaOf<T>>),
+        /// Id usage for target reset
+        UsageReset(RoleId, QuotaTargetOf<T>),
+        /// Id requires approval for call with hash, or not
+        ApprovalRequirementUpdated(RoleId, CallHashOf<T>, bool),
+        /// Account proposed call with hash for Id
+        CallProposed(RoleId, CallHashOf<T>, T::AccountId),
+        /// Account approved call with hash for Id
+        CallApproved(RoleId, CallHashOf<T>, T::AccountId),
+        /// Proposed call with hash for Id dispatched with result
+        ProposalExecuted(RoleId, CallHashOf<T>, DispatchResult),
+        /// Proposed call with hash for Id cancelled
+        ProposalCancelled(RoleId, CallHashOf<T>),
+        /// Change announced, to be applied at block
+        ChangeAnnounced(ChangeId, Change<T, I>, BlockNumberFor<T>),
+        /// Announced change applied with result
+        ChangeApplied(ChangeId, DispatchResult),
+        /// Announced change cancelled
+        ChangeCancelled(ChangeId),
+        /// Account executed call with hash as Executer of Id, dispatch result included
+        CallExecuted {
+            who: T::AccountId,
+            role_id: RoleId,
+            call_hash: CallHashOf<T>,
+            result: DispatchResult,
+        },
+        /// Execution paused for Id
+        RolePaused(RoleId),
+        /// Execution unpaused for Id
+        RoleUnpaused(RoleId),
+        /// Execution paused for all ids
+        AllPaused,
+        /// Execution unpaused for all ids
+        AllUnpaused,
+        /// Id created with Account as owner
+        RoleCreated(RoleId, T::AccountId),
+        /// Id and all of its storage removed
+        RoleDestroyed(RoleId),
+        /// Id metadata updated
+        RoleMetadataSet(RoleId),
+    }
+
+    /// Reasons for holding deposits
+    #[pallet::composite_enum]
+    pub enum HoldReason<I: 'static = ()> {
+        /// Deposit for a grant
+        Grant,
+        /// Deposit for a call accessible to an id
+        Call,
     }
 
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         /// A role does not exist in storage
         AccessDNE,
         /// Call not permitted to user
@@ -138,52 +526,342 @@ pub mod pallet {
         AlreadyGrantedAccess,
         /// Caller is not admin
         CallerNotAdmin,
-        /// Number of calls exceeds `set_call` input limits
+        /// Number of calls for an id exceeds `MaxCalls`
         TooManyCalls,
         /// Encoding call as (u8, u8) failed
         EncodingFailed,
         /// Origin not set to dispatch call
         CallOriginNotSet,
+        /// Account is Executer for `MaxRolesPerAccount` ids
+        TooManyRoles,
+        /// Role account cannot pay the fee for the call
+        InsufficientRoleFunds,
+        /// Encoded call exceeds `MaxCallLen`
+        CallTooLong,
+        /// Expiry block must be after the current block
+        ExpiryInPast,
+        /// Execution exceeds a quota of the id
+        QuotaExceeded,
+        /// Call must be proposed and approved
+        CallRequiresApproval,
+        /// Call does not require approval
+        ApprovalNotRequired,
+        /// Call already proposed for the id
+        ProposalExists,
+        /// Proposal does not exist
+        ProposalDNE,
+        /// Proposal expired
+        ProposalExpired,
+        /// Caller already approved the proposal
+        AlreadyApproved,
+        /// Proposer cannot approve their own proposal
+        ProposerCannotApprove,
+        /// Input max weight is below the weight of the proposed call
+        MaxWeightTooLow,
+        /// Announced change does not exist
+        ChangeDNE,
+        /// Execution is paused for the id
+        Paused,
+        /// Id was not created with `create_role`
+        RoleDNE,
+        /// Id is being destroyed
+        RoleDestroying,
+        /// Caller is not the owner of the id
+        CallerNotOwner,
+        /// Change is too heavy to be applied in `on_idle`
+        ChangeTooHeavy,
     }
 
-    /// Id, Account => Option<Role>
+    /// Id, Account => Option<Grant>
     #[pallet::storage]
-    pub type Roles<T: Config> = StorageDoubleMap<
+    pub type Roles<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         RoleId,
         Blake2_128Concat,
         T::AccountId,
-        Role,
+        GrantOf<T>,
         OptionQuery,
     >;
 
     /// Account, Id => Option<()>
     #[pallet::storage]
-    pub type Permissions<T: Config> = StorageDoubleMap<
+    pub type Permissions<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        RoleId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Account => Number of ids in Permissions
+    #[pallet::storage]
+    pub type PermissionCount<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// CallHash => Option<(EncodedCall, RefCount)>
+    #[pallet::storage]
+    pub type Calls<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, CallHashOf<T>, (EncodedCallOf<T, I>, u32), OptionQuery>;
+
+    /// Id, CallHash => Option<Origin>
+    #[pallet::storage]
+    pub type CallOrigins<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RoleId,
+        Identity,
+        CallHashOf<T>,
+        <T as Config<I>>::PalletsOrigin,
+        OptionQuery,
+    >;
+
+    /// Id => Number of calls in CallOrigins
+    #[pallet::storage]
+    pub type CallCount<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, RoleId, u32, ValueQuery>;
+
+    /// Id, CallIndex => Option<Origin>
+    #[pallet::storage]
+    pub type CallIndexOrigins<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RoleId,
+        Blake2_128Concat,
+        CallIndex,
+        <T as Config<I>>::PalletsOrigin,
+        OptionQuery,
+    >;
+
+    /// Id, Constraint => Option<Origin>
+    #[pallet::storage]
+    pub type CallConstraints<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RoleId,
+        Blake2_128Concat,
+        <T as Config<I>>::CallConstraint,
+        <T as Config<I>>::PalletsOrigin,
+        OptionQuery,
+    >;
+
+    /// Id => FeePolicy
+    #[pallet::storage]
+    pub type FeePolicies<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, RoleId, FeePolicy, ValueQuery>;
+
+    /// Id, QuotaTarget => Option<Quota>
+    #[pallet::storage]
+    pub type Quotas<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RoleId,
+        Blake2_128Concat,
+        QuotaTargetOf<T>,
+        QuotaOf<T>,
+        OptionQuery,
+    >;
+
+    /// Id, QuotaTarget => Usage
+    #[pallet::storage]
+    pub type Usages<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RoleId,
+        Blake2_128Concat,
+        QuotaTargetOf<T>,
+        UsageOf<T>,
+        ValueQuery,
+    >;
+
+    /// Id, CallHash => Option<()>
+    #[pallet::storage]
+    pub type ApprovalRequired<T: Config<I>, I: 'static = ()> =
+        StorageDoubleMap<_, Blake2_128Concat, RoleId, Identity, CallHashOf<T>, (), OptionQuery>;
+
+    /// Id, CallHash => Option<Proposal>
+    #[pallet::storage]
+    pub type Proposals<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RoleId,
+        Identity,
+        CallHashOf<T>,
+        ProposalOf<T>,
+        OptionQuery,
+    >;
+
+    /// (Id, CallHash), Account => Option<()>
+    #[pallet::storage]
+    pub type Approvals<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
         _,
         Blake2_128Concat,
+        (RoleId, CallHashOf<T>),
+        Blake2_128Concat,
         T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Id => Option<()>
+    #[pallet::storage]
+    pub type PausedRoles<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, RoleId, (), OptionQuery>;
+
+    /// Execution paused for all ids
+    #[pallet::storage]
+    pub type PausedAll<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+    /// ChangeId => Option<(Block, Actor, Change)>
+    #[pallet::storage]
+    pub type PendingChanges<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
+        Twox64Concat,
+        ChangeId,
+        (BlockNumberFor<T>, Option<T::AccountId>, Change<T, I>),
+        OptionQuery,
+    >;
+
+    /// Id => most recent privileged actions, oldest first
+    #[pallet::storage]
+    pub type AuditLog<T: Config<I>, I: 'static = ()> = StorageMap<
+        _,
         Blake2_128Concat,
         RoleId,
+        BoundedVec<AuditEntryOf<T>, T::AuditLogDepth>,
+        ValueQuery,
+    >;
+
+    /// Id of the next announced change
+    #[pallet::storage]
+    pub type NextChangeId<T: Config<I>, I: 'static = ()> = StorageValue<_, ChangeId, ValueQuery>;
+
+    /// Id of the next announced change to be applied
+    #[pallet::storage]
+    pub type ChangeCursor<T: Config<I>, I: 'static = ()> = StorageValue<_, ChangeId, ValueQuery>;
+
+    /// Expiry, (Id, Account) => Option<()>
+    #[pallet::storage]
+    pub type ExpiryQueue<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (RoleId, T::AccountId),
         (),
         OptionQuery,
     >;
 
-    /// Id, Call => Option<Origin>
+    /// Next block in ExpiryQueue to be expired
+    #[pallet::storage]
+    pub type ExpiryCursor<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Id => Option<RoleInfo>
     #[pallet::storage]
-    pub type CallOrigins<T: Config> = StorageDoubleMap<
+    pub type RoleInfos<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, RoleId, RoleInfoOf<T, I>, OptionQuery>;
+
+    /// Id => Option<RoleMetadata>
+    #[pallet::storage]
+    pub type Metadata<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, RoleId, RoleMetadataOf<T, I>, OptionQuery>;
+
+    /// Id, Account => Option<(depositor, deposit)> for the grant of Account
+    #[pallet::storage]
+    pub type GrantDeposits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         RoleId,
         Blake2_128Concat,
-        <T as Config>::RuntimeCall,
-        <T as Config>::PalletsOrigin,
+        T::AccountId,
+        (T::AccountId, BalanceOf<T, I>),
+        OptionQuery,
+    >;
+
+    /// Id, CallHash => Option<(depositor, deposit)> for the `CallOrigins` entry
+    #[pallet::storage]
+    pub type CallDeposits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RoleId,
+        Identity,
+        CallHashOf<T>,
+        (T::AccountId, BalanceOf<T, I>),
         OptionQuery,
     >;
 
+    /// Id of the next role created
+    #[pallet::storage]
+    pub type NextRoleId<T: Config<I>, I: 'static = ()> = StorageValue<_, RoleId, ValueQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+        #[serde(skip)]
+        pub phantom: PhantomData<I>,
+        /// Owners of the ids created at genesis, in order of id starting at 0
+        pub owners: Vec<T::AccountId>,
+        /// Id, Account, Role assignments
+        pub roles: Vec<(RoleId, T::AccountId, Role)>,
+        /// Id, SCALE encoded `CallAndOrigin`s accessible to Executers of Id
+        pub calls: Vec<(RoleId, Vec<Vec<u8>>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
+        fn build(&self) {
+            for owner in self.owners.iter() {
+                Pallet::<T, I>::do_create_role(owner.clone(), Default::default());
+            }
+            for (id, who, role) in self.roles.iter() {
+                Pallet::<T, I>::ensure_role_exists(*id).expect("genesis roles are for created ids");
+                Pallet::<T, I>::do_grant(*id, who, *role, None)
+                    .expect("genesis roles are unique and within MaxRolesPerAccount");
+            }
+            for (id, calls) in self.calls.iter() {
+                Pallet::<T, I>::ensure_role_exists(*id).expect("genesis calls are for created ids");
+                assert!(
+                    CallCount::<T, I>::get(id) == 0,
+                    "genesis calls are set once per id"
+                );
+                let calls = calls
+                    .iter()
+                    .map(|encoded| {
+                        let CallOrigin { call, origin } =
+                            CallAndOrigin::<T, I>::decode(&mut &encoded[..])
+                                .expect("genesis calls are SCALE encoded CallAndOrigin");
+                        let call = Pallet::<T, I>::encode_call(&call)
+                            .expect("genesis calls are within MaxCallLen");
+                        (call, origin)
+                    })
+                    .collect();
+                Pallet::<T, I>::do_add_calls(*id, calls, None)
+                    .expect("genesis calls are within MaxCalls");
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let used = Self::apply_changes(now, remaining_weight);
+            used.saturating_add(Self::expire_grants(
+                now,
+                remaining_weight.saturating_sub(used),
+            ))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
         /// For input `who` grants access to calls allowed by Executors of input `id`
         /// Only succeeds if (i) the caller is SuperUser or (ii) the caller is an `id` Admin and `who` is an `id` Executor
         /// Fails if `who` already occupies a role for `id`
@@ -195,23 +873,42 @@ pub mod pallet {
             who: T::AccountId,
             role: Role,
         ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
+            let is_admin_not_super = Self::ensure_origin(origin, id)?;
+            if is_admin_not_super {
+                ensure!(
+                    matches!(role, Role::Executer),
+                    Error::<T, I>::AdminOnlyGrantsExecuterAccess
+                );
+            }
+            Self::announce(actor, Change::Grant(id, who, role, None))
+        }
+
+        /// For input `who` grants access until the `expiry` block.
+        /// Expired access is treated as absent and removed when the chain is idle.
+        /// Same origin requirements as `grant_access`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::grant_access_until())]
+        pub fn grant_access_until(
+            origin: OriginFor<T>,
+            id: RoleId,
+            who: T::AccountId,
+            role: Role,
+            expiry: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
             let is_admin_not_super = Self::ensure_origin(origin, id)?;
             if is_admin_not_super {
                 ensure!(
                     matches!(role, Role::Executer),
-                    Error::<T>::AdminOnlyGrantsExecuterAccess
+                    Error::<T, I>::AdminOnlyGrantsExecuterAccess
                 );
             }
             ensure!(
-                Roles::<T>::get(id, &who).is_none(),
-                Error::<T>::AlreadyGrantedAccess
+                expiry > frame_system::Pallet::<T>::block_number(),
+                Error::<T, I>::ExpiryInPast
             );
-            if matches!(role, Role::Executer) {
-                Permissions::<T>::insert(&who, id, ());
-            }
-            Roles::<T>::insert(id, &who, role);
-            Self::deposit_event(Event::AccessGranted(id, who, role));
-            Ok(())
+            Self::announce(actor, Change::Grant(id, who, role, Some(expiry)))
         }
 
         /// For input `who` revoke access to calls allowed by Executors of input `id`
@@ -223,20 +920,16 @@ pub mod pallet {
             id: RoleId,
             who: T::AccountId,
         ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
             let is_admin_not_super = Self::ensure_origin(origin, id)?;
-            let role = Roles::<T>::get(id, &who).ok_or(Error::<T>::AccessDNE)?;
+            let grant = Roles::<T, I>::get(id, &who).ok_or(Error::<T, I>::AccessDNE)?;
             if is_admin_not_super {
                 ensure!(
-                    matches!(role, Role::Executer),
-                    Error::<T>::AdminOnlyRevokesExecuterAccess
+                    matches!(grant.role, Role::Executer),
+                    Error::<T, I>::AdminOnlyRevokesExecuterAccess
                 );
             }
-            if matches!(role, Role::Executer) {
-                Permissions::<T>::remove(&who, id);
-            }
-            Roles::<T>::remove(id, &who);
-            Self::deposit_event(Event::AccessRevoked(id, who, role));
-            Ok(())
+            Self::announce(actor, Change::Revoke(id, who))
         }
 
         /// Set dispatchable calls accessible to Executors of the input `id`.
@@ -246,57 +939,501 @@ pub mod pallet {
         pub fn set_calls(
             origin: OriginFor<T>,
             id: RoleId,
-            calls: Vec<CallAndOrigin<T>>,
+            calls: BoundedVec<CallAndOrigin<T, I>, T::MaxCalls>,
         ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
             T::SuperUser::ensure_origin(origin)?;
-            ensure!(
-                calls.len() <= T::MaxCalls::get() as usize,
-                Error::<T>::TooManyCalls
-            );
-            let _ = CallOrigins::<T>::clear_prefix(id, u32::MAX, None);
-            for CallOrigin { call, origin } in calls.into_iter() {
-                CallOrigins::<T>::insert(id, call, origin);
-            }
-            Self::deposit_event(Event::CallsUpdated(id));
-            Ok(())
+            Self::announce(actor, Change::SetCalls(id, Self::encode_calls(calls)?))
+        }
+
+        /// Add dispatchable calls accessible to Executors of the input `id`.
+        /// Updates the origin of calls already accessible.
+        /// Fails if the number of calls for `id` would exceed `MaxCalls`.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::add_calls(calls.len() as u32))]
+        pub fn add_calls(
+            origin: OriginFor<T>,
+            id: RoleId,
+            calls: BoundedVec<CallAndOrigin<T, I>, T::MaxCalls>,
+        ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
+            T::SuperUser::ensure_origin(origin)?;
+            Self::announce(actor, Change::AddCalls(id, Self::encode_calls(calls)?))
+        }
+
+        /// Remove dispatchable calls accessible to Executors of the input `id`.
+        /// Fails if any call is not accessible.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::remove_calls(calls.len() as u32))]
+        pub fn remove_calls(
+            origin: OriginFor<T>,
+            id: RoleId,
+            calls: BoundedVec<<T as Config<I>>::RuntimeCall, T::MaxCalls>,
+        ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
+            T::SuperUser::ensure_origin(origin)?;
+            let hashes = calls
+                .iter()
+                .map(|call| T::Hashing::hash_of(call))
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| Error::<T, I>::TooManyCalls)?;
+            Self::announce(actor, Change::RemoveCalls(id, hashes))
+        }
+
+        /// Dispatch call from its origin iff caller is a member of Executor
+        /// for the input `id` and `id` has access to the call.
+        /// Unlike `execute_call`, the dispatch origin does not depend on the other ids of the caller.
+        /// Succeeds even if the dispatched call fails, its result is in `CallExecuted`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(
+			T::WeightInfo::execute_call_with_role()
+				.saturating_add(call.get_dispatch_info().weight)
+		)]
+        pub fn execute_call_with_role(
+            origin: OriginFor<T>,
+            id: RoleId,
+            call: Box<<T as Config<I>>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let from = T::ValidateCall::validate_call_with_role(id, &who, &call)
+                .ok_or(Error::<T, I>::CallNotPermitted)?;
+            Self::do_execute(
+                id,
+                &who,
+                from,
+                *call,
+                T::WeightInfo::execute_call_with_role(),
+            )
         }
 
         /// Dispatch call from its origin iff caller is a member of Executor
         /// for an Id that has access to the call.
+        /// Succeeds even if the dispatched call fails, its result is in `CallExecuted`.
         #[pallet::call_index(3)]
         #[pallet::weight(
-			T::WeightInfo::execute_call()
+			T::WeightInfo::execute_call(T::MaxRolesPerAccount::get())
 				.saturating_add(call.get_dispatch_info().weight)
 		)]
         pub fn execute_call(
             origin: OriginFor<T>,
-            call: Box<<T as Config>::RuntimeCall>,
+            call: Box<<T as Config<I>>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let (id, from) = T::ValidateCall::validate_call(&who, &call)
+                .ok_or(Error::<T, I>::CallNotPermitted)?;
+            let weight = T::WeightInfo::execute_call(PermissionCount::<T, I>::get(&who));
+            Self::do_execute(id, &who, from, *call, weight)
+        }
+
+        /// Transfer `amount` from the caller to the account of the input `id`.
+        /// The role account pays fees for Executors according to the `id` fee policy.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::fund_role())]
+        pub fn fund_role(
+            origin: OriginFor<T>,
+            id: RoleId,
+            amount: BalanceOf<T, I>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let from =
-                T::ValidateCall::validate_call(&who, &call).ok_or(Error::<T>::CallNotPermitted)?;
-            call.dispatch(from).map_err(|e| e.error)?;
+            Self::ensure_role_exists(id)?;
+            T::Currency::transfer(
+                &who,
+                &Self::role_account(id),
+                amount,
+                Preservation::Preserve,
+            )?;
+            Self::deposit_event(Event::RoleFunded(id, who, amount));
             Ok(())
         }
-    }
 
-    impl<T: Config> ValidateCall<T> for Pallet<T> {
-        /// Return Ok(dispatch_origin) if input account is permitted to make the
-        /// call due to membership as an Executer with the permitted ID
-        fn validate_call(
-            who: &T::AccountId,
-            call: &<T as Config>::RuntimeCall,
-        ) -> Option<<T as Config>::RuntimeOrigin> {
-            for (id, _) in Permissions::<T>::iter_prefix(&who) {
-                if let Some(origin) = CallOrigins::<T>::get(id, call) {
-                    return Some(origin.into());
-                }
-            }
-            None
-        }
+        /// Set who pays the fees for calls executed by Executors of the input `id`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_fee_policy())]
+        pub fn set_fee_policy(
+            origin: OriginFor<T>,
+            id: RoleId,
+            policy: FeePolicy,
+        ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
+            T::SuperUser::ensure_origin(origin)?;
+            Self::announce(actor, Change::SetFeePolicy(id, policy))
+        }
+
+        /// Set or remove (`None`) the quota of the input `target` for the input `id`.
+        /// Usage counted so far is kept.
+        /// Must be called by the `SuperUser` or an `Admin` for the input `id`.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::set_quota())]
+        pub fn set_quota(
+            origin: OriginFor<T>,
+            id: RoleId,
+            target: QuotaTargetOf<T>,
+            quota: Option<QuotaOf<T>>,
+        ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
+            Self::ensure_origin(origin, id)?;
+            Self::announce(actor, Change::SetQuota(id, target, quota))
+        }
+
+        /// Reset the usage counted against the quota of the input `target` for the input `id`.
+        /// Must be called by the `SuperUser` or an `Admin` for the input `id`.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::reset_usage())]
+        pub fn reset_usage(
+            origin: OriginFor<T>,
+            id: RoleId,
+            target: QuotaTargetOf<T>,
+        ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
+            Self::ensure_origin(origin, id)?;
+            Self::announce(actor, Change::ResetUsage(id, target))
+        }
+
+        /// Set whether the call with the input `call_hash` must be proposed and approved
+        /// before it is dispatched for the input `id`. Must be called by the `SuperUser`.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_approval_required())]
+        pub fn set_approval_required(
+            origin: OriginFor<T>,
+            id: RoleId,
+            call_hash: CallHashOf<T>,
+            required: bool,
+        ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
+            T::SuperUser::ensure_origin(origin)?;
+            Self::announce(actor, Change::SetApprovalRequired(id, call_hash, required))
+        }
+
+        /// Propose the call with the input `call_hash` which requires approval for the input `id`.
+        /// Must be called by an Executer of the input `id`. Replaces an expired proposal.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::propose_call())]
+        pub fn propose_call(
+            origin: OriginFor<T>,
+            id: RoleId,
+            call_hash: CallHashOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                matches!(Self::role(id, &who), Some(Role::Executer)),
+                Error::<T, I>::CallNotPermitted
+            );
+            ensure!(
+                CallOrigins::<T, I>::contains_key(id, call_hash),
+                Error::<T, I>::CallNotPermitted
+            );
+            ensure!(
+                ApprovalRequired::<T, I>::contains_key(id, call_hash),
+                Error::<T, I>::ApprovalNotRequired
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(proposal) = Proposals::<T, I>::get(id, call_hash) {
+                // an expired proposal is replaced
+                ensure!(proposal.expiry <= now, Error::<T, I>::ProposalExists);
+                Self::remove_proposal(id, call_hash);
+            }
+            let expiry = now.saturating_add(T::ProposalLifetime::get());
+            Proposals::<T, I>::insert(
+                id,
+                call_hash,
+                Proposal {
+                    proposer: who.clone(),
+                    expiry,
+                    approvals: 0,
+                },
+            );
+            Self::deposit_event(Event::CallProposed(id, call_hash, who));
+            Ok(())
+        }
+
+        /// Approve the proposed call with the input `call_hash` for the input `id`.
+        /// Must be called by an Admin or Executer of the input `id` other than the proposer.
+        /// Dispatches the call with its origin once `ApprovalThreshold` accounts approved,
+        /// counting it against the quotas of the proposer and the call.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::approve_call().saturating_add(*max_weight))]
+        pub fn approve_call(
+            origin: OriginFor<T>,
+            id: RoleId,
+            call_hash: CallHashOf<T>,
+            max_weight: Weight,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Self::role(id, &who).is_some(),
+                Error::<T, I>::CallNotPermitted
+            );
+            let mut proposal =
+                Proposals::<T, I>::get(id, call_hash).ok_or(Error::<T, I>::ProposalDNE)?;
+            ensure!(
+                proposal.expiry > frame_system::Pallet::<T>::block_number(),
+                Error::<T, I>::ProposalExpired
+            );
+            ensure!(
+                proposal.proposer != who,
+                Error::<T, I>::ProposerCannotApprove
+            );
+            ensure!(
+                !Approvals::<T, I>::contains_key((id, call_hash), &who),
+                Error::<T, I>::AlreadyApproved
+            );
+            Approvals::<T, I>::insert((id, call_hash), &who, ());
+            proposal.approvals = proposal.approvals.saturating_add(1);
+            Self::deposit_event(Event::CallApproved(id, call_hash, who));
+            if proposal.approvals < T::ApprovalThreshold::get() {
+                Proposals::<T, I>::insert(id, call_hash, proposal);
+                return Ok(Some(T::WeightInfo::approve_call()).into());
+            }
+            let call = Self::stored_call(call_hash).ok_or(Error::<T, I>::CallOriginNotSet)?;
+            let from =
+                CallOrigins::<T, I>::get(id, call_hash).ok_or(Error::<T, I>::CallOriginNotSet)?;
+            ensure!(!Self::is_paused(id), Error::<T, I>::Paused);
+            let info = call.get_dispatch_info();
+            ensure!(
+                max_weight.all_gte(info.weight),
+                Error::<T, I>::MaxWeightTooLow
+            );
+            Self::use_quota(id, QuotaTarget::Executer(proposal.proposer.clone()))?;
+            Self::use_quota(id, QuotaTarget::Call(call_hash))?;
+            Self::remove_proposal(id, call_hash);
+            let result = call.dispatch(from.into());
+            let call_weight = extract_actual_weight(&result, &info);
+            Self::audit(
+                id,
+                Some(proposal.proposer),
+                AuditAction::Executed(call_hash),
+            );
+            Self::deposit_event(Event::ProposalExecuted(
+                id,
+                call_hash,
+                result.map(|_| ()).map_err(|e| e.error),
+            ));
+            Ok(Some(T::WeightInfo::approve_call().saturating_add(call_weight)).into())
+        }
+
+        /// Cancel the proposed call with the input `call_hash` for the input `id`.
+        /// Must be called by the proposer, an Admin of the input `id` or the `SuperUser`,
+        /// unless the proposal expired.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::cancel_proposal())]
+        pub fn cancel_proposal(
+            origin: OriginFor<T>,
+            id: RoleId,
+            call_hash: CallHashOf<T>,
+        ) -> DispatchResult {
+            let proposal =
+                Proposals::<T, I>::get(id, call_hash).ok_or(Error::<T, I>::ProposalDNE)?;
+            if proposal.expiry > frame_system::Pallet::<T>::block_number() {
+                if let Err(origin) = T::SuperUser::try_origin(origin) {
+                    let who = ensure_signed(origin)?;
+                    ensure!(
+                        who == proposal.proposer
+                            || matches!(Self::role(id, &who), Some(Role::Admin)),
+                        Error::<T, I>::CallerNotAdmin
+                    );
+                }
+            }
+            Self::remove_proposal(id, call_hash);
+            Self::deposit_event(Event::ProposalCancelled(id, call_hash));
+            Ok(())
+        }
+
+        /// Refuse execution of calls for the input `id` until unpaused. Grants are kept.
+        /// Must be called by the `PauseOrigin` or the `SuperUser`.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::pause_role())]
+        pub fn pause_role(origin: OriginFor<T>, id: RoleId) -> DispatchResult {
+            Self::ensure_pause_origin(origin)?;
+            PausedRoles::<T, I>::insert(id, ());
+            Self::deposit_event(Event::RolePaused(id));
+            Ok(())
+        }
+
+        /// Allow execution of calls for the input `id` again, unless it is being destroyed.
+        /// Must be called by the `PauseOrigin` or the `SuperUser`.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::unpause_role())]
+        pub fn unpause_role(origin: OriginFor<T>, id: RoleId) -> DispatchResult {
+            Self::ensure_pause_origin(origin)?;
+            ensure!(
+                !RoleInfos::<T, I>::get(id).map_or(false, |info| info.destroying),
+                Error::<T, I>::RoleDestroying
+            );
+            PausedRoles::<T, I>::remove(id);
+            Self::deposit_event(Event::RoleUnpaused(id));
+            Ok(())
+        }
+
+        /// Refuse execution of calls for all ids until unpaused. Grants are kept.
+        /// Must be called by the `PauseOrigin` or the `SuperUser`.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::pause_all())]
+        pub fn pause_all(origin: OriginFor<T>) -> DispatchResult {
+            Self::ensure_pause_origin(origin)?;
+            PausedAll::<T, I>::put(true);
+            Self::deposit_event(Event::AllPaused);
+            Ok(())
+        }
+
+        /// Allow execution of calls for ids which are not paused individually.
+        /// Must be called by the `PauseOrigin` or the `SuperUser`.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::unpause_all())]
+        pub fn unpause_all(origin: OriginFor<T>) -> DispatchResult {
+            Self::ensure_pause_origin(origin)?;
+            PausedAll::<T, I>::kill();
+            Self::deposit_event(Event::AllUnpaused);
+            Ok(())
+        }
+
+        /// Cancel the announced change with the input `change_id` before it is applied.
+        /// Must be called by the `SuperUser`.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::cancel_pending_change())]
+        pub fn cancel_pending_change(origin: OriginFor<T>, change_id: ChangeId) -> DispatchResult {
+            T::SuperUser::ensure_origin(origin)?;
+            ensure!(
+                PendingChanges::<T, I>::take(change_id).is_some(),
+                Error::<T, I>::ChangeDNE
+            );
+            Self::deposit_event(Event::ChangeCancelled(change_id));
+            Ok(())
+        }
+
+        /// Set call constraints accessible to Executors of the input `id`.
+        /// Calls matching a constraint are dispatched from its respective origin.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_call_constraints(constraints.len() as u32))]
+        pub fn set_call_constraints(
+            origin: OriginFor<T>,
+            id: RoleId,
+            constraints: BoundedVec<ConstraintAndOrigin<T, I>, T::MaxCalls>,
+        ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
+            T::SuperUser::ensure_origin(origin)?;
+            Self::announce(actor, Change::SetCallConstraints(id, constraints))
+        }
+
+        /// Set pallet or call indices accessible to Executors of the input `id`.
+        /// Calls at an index are dispatched from its respective origin.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_call_indices(indices.len() as u32))]
+        pub fn set_call_indices(
+            origin: OriginFor<T>,
+            id: RoleId,
+            indices: BoundedVec<CallIndexAndOrigin<T, I>, T::MaxCalls>,
+        ) -> DispatchResult {
+            let actor = ensure_signed(origin.clone()).ok();
+            T::SuperUser::ensure_origin(origin)?;
+            Self::announce(actor, Change::SetCallIndices(id, indices))
+        }
+
+        /// Create a role with the next id, owned by the input `owner`.
+        /// Must be called by the `SuperUser`.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::create_role())]
+        pub fn create_role(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            metadata: BoundedVec<u8, T::MaxMetadataLen>,
+        ) -> DispatchResult {
+            T::SuperUser::ensure_origin(origin)?;
+            let id = Self::do_create_role(owner.clone(), metadata);
+            Self::deposit_event(Event::RoleCreated(id, owner));
+            Ok(())
+        }
+
+        /// Destroy the input `id`, removing up to `limit` of its grants, calls, proposals,
+        /// quotas and usages per call. Execution is paused from the first call and the id is
+        /// removed by the call which finds nothing left, refunding the role account to the
+        /// owner. Must be called by the owner or the `SuperUser`.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::destroy_role(*limit))]
+        pub fn destroy_role(
+            origin: OriginFor<T>,
+            id: RoleId,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            let mut info = RoleInfos::<T, I>::get(id).ok_or(Error::<T, I>::RoleDNE)?;
+            Self::ensure_owner(origin, &info)?;
+            if !info.destroying {
+                info.destroying = true;
+                RoleInfos::<T, I>::insert(id, &info);
+                PausedRoles::<T, I>::insert(id, ());
+            }
+            let removed = Self::destroy_entries(id, limit);
+            if removed < limit {
+                Self::finish_destroy(id, &info.owner);
+                Self::deposit_event(Event::RoleDestroyed(id));
+            }
+            // the weight of `limit` covers `limit - 1` entries and finishing
+            Ok(Some(T::WeightInfo::destroy_role(
+                removed.saturating_add(1).min(limit),
+            ))
+            .into())
+        }
+
+        /// Set the name, description and tags of the input `id`, replacing any previous
+        /// metadata. Must be called by the owner or the `SuperUser`.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::set_role_metadata())]
+        pub fn set_role_metadata(
+            origin: OriginFor<T>,
+            id: RoleId,
+            name: BoundedVec<u8, T::MaxNameLen>,
+            description: BoundedVec<u8, T::MaxDescriptionLen>,
+            tags: BoundedVec<BoundedVec<u8, T::MaxTagLen>, T::MaxTags>,
+        ) -> DispatchResult {
+            let info = RoleInfos::<T, I>::get(id).ok_or(Error::<T, I>::RoleDNE)?;
+            Self::ensure_owner(origin, &info)?;
+            ensure!(!info.destroying, Error::<T, I>::RoleDestroying);
+            Metadata::<T, I>::insert(
+                id,
+                RoleMetadata {
+                    name,
+                    description,
+                    tags,
+                },
+            );
+            Self::deposit_event(Event::RoleMetadataSet(id));
+            Ok(())
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> ValidateCall<T, I> for Pallet<T, I> {
+        /// Return Ok(dispatch_origin) if input account is permitted to make the
+        /// call due to membership as an Executer with the permitted ID
+        fn validate_call(
+            who: &T::AccountId,
+            call: &<T as Config<I>>::RuntimeCall,
+        ) -> Option<(RoleId, <T as Config<I>>::RuntimeOrigin)> {
+            Self::can_execute(who, call).map(|(id, origin)| (id, origin.into()))
+        }
+
+        /// Return Ok(dispatch_origin) if input account is an Executer for the
+        /// input ID and the ID is permitted to make the call
+        fn validate_call_with_role(
+            id: RoleId,
+            who: &T::AccountId,
+            call: &<T as Config<I>>::RuntimeCall,
+        ) -> Option<<T as Config<I>>::RuntimeOrigin> {
+            if !matches!(Self::role(id, who), Some(Role::Executer)) || Self::is_paused(id) {
+                return None;
+            }
+            Self::call_origin(id, call, T::Hashing::hash_of(call)).map(Into::into)
+        }
     }
     // Private functions
-    impl<T: Config> Pallet<T> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Ensures origin is the PauseOrigin or SuperUser.
+        fn ensure_pause_origin(origin: OriginFor<T>) -> DispatchResult {
+            if T::PauseOrigin::ensure_origin(origin.clone()).is_err() {
+                T::SuperUser::ensure_origin(origin)?;
+            }
+            Ok(())
+        }
+
         /// Ensures origin is SuperUser or an id Admin.
         /// Returns:
         /// Ok(true) if an id Admin (and not a super user)
@@ -305,27 +1442,968 @@ pub mod pallet {
         fn ensure_origin(origin: OriginFor<T>, id: RoleId) -> Result<bool, DispatchError> {
             if let Err(e) = T::SuperUser::ensure_origin(origin.clone()) {
                 let caller = ensure_signed(origin)?;
-                let Some(role) = Roles::<T>::get(id, caller) else {
+                let Some(role) = Self::role(id, &caller) else {
                     return Err(e.into());
                 };
-                ensure!(matches!(role, Role::Admin), Error::<T>::CallerNotAdmin);
+                ensure!(matches!(role, Role::Admin), Error::<T, I>::CallerNotAdmin);
                 Ok(true)
             } else {
                 Ok(false)
             }
         }
+
+        /// Dispatch call from origin after the id account pays the fee according to the id fee policy.
+        /// `weight` excludes the weight of the call.
+        fn do_execute(
+            id: RoleId,
+            who: &T::AccountId,
+            from: <T as Config<I>>::RuntimeOrigin,
+            call: <T as Config<I>>::RuntimeCall,
+            weight: Weight,
+        ) -> DispatchResultWithPostInfo {
+            let info = call.get_dispatch_info();
+            ensure!(!Self::is_paused(id), Error::<T, I>::Paused);
+            let hash = T::Hashing::hash_of(&call);
+            ensure!(
+                !ApprovalRequired::<T, I>::contains_key(id, hash),
+                Error::<T, I>::CallRequiresApproval
+            );
+            Self::use_quota(id, QuotaTarget::Executer(who.clone()))?;
+            Self::use_quota(id, QuotaTarget::Call(hash))?;
+            let pays_fee = Self::pay_fee(id, who, weight.saturating_add(info.weight))?;
+            let result = call.dispatch(from);
+            let call_weight = extract_actual_weight(&result, &info);
+            Self::audit(id, Some(who.clone()), AuditAction::Executed(hash));
+            Self::deposit_event(Event::CallExecuted {
+                who: who.clone(),
+                role_id: id,
+                call_hash: hash,
+                result: result.map(|_| ()).map_err(|e| e.error),
+            });
+            Ok(PostDispatchInfo {
+                actual_weight: Some(weight.saturating_add(call_weight)),
+                pays_fee,
+            })
+        }
+
+        /// Assign role for id to who, optionally until the expiry block.
+        /// Fails if who already occupies a role for id or is Executer for `MaxRolesPerAccount` ids.
+        pub(crate) fn do_grant(
+            id: RoleId,
+            who: &T::AccountId,
+            role: Role,
+            expiry: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            if let Some(grant) = Roles::<T, I>::get(id, who) {
+                ensure!(
+                    Self::is_expired(&grant),
+                    Error::<T, I>::AlreadyGrantedAccess
+                );
+                Self::do_revoke(id, who, grant);
+            }
+            if matches!(role, Role::Executer) {
+                PermissionCount::<T, I>::try_mutate(who, |count| {
+                    ensure!(
+                        *count < T::MaxRolesPerAccount::get(),
+                        Error::<T, I>::TooManyRoles
+                    );
+                    *count = count.saturating_add(1);
+                    Ok::<(), DispatchError>(())
+                })?;
+                Permissions::<T, I>::insert(who, id, ());
+            }
+            if let Some(expiry) = expiry {
+                ExpiryQueue::<T, I>::insert(expiry, (id, who.clone()), ());
+                ExpiryCursor::<T, I>::mutate(|cursor| {
+                    *cursor = Some(cursor.map_or(expiry, |cursor| cursor.min(expiry)))
+                });
+            }
+            Roles::<T, I>::insert(id, who, Grant { role, expiry });
+            Ok(())
+        }
+
+        /// Remove grant for id from who.
+        pub(crate) fn do_revoke(id: RoleId, who: &T::AccountId, grant: GrantOf<T>) {
+            if let Some(expiry) = grant.expiry {
+                ExpiryQueue::<T, I>::remove(expiry, (id, who.clone()));
+            }
+            if matches!(grant.role, Role::Executer) {
+                Permissions::<T, I>::remove(who, id);
+                PermissionCount::<T, I>::mutate_exists(who, |count| {
+                    *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+                });
+            }
+            Roles::<T, I>::remove(id, who);
+            Self::release_grant_deposit(id, who);
+        }
+
+        /// Encode calls, failing if any call exceeds `MaxCallLen`
+        pub(crate) fn encode_calls(
+            calls: BoundedVec<CallAndOrigin<T, I>, T::MaxCalls>,
+        ) -> Result<EncodedCallsOf<T, I>, DispatchError> {
+            let calls = calls
+                .into_iter()
+                .map(|CallOrigin { call, origin }| Ok((Self::encode_call(&call)?, origin)))
+                .collect::<Result<Vec<_>, DispatchError>>()?;
+            Ok(BoundedVec::truncate_from(calls))
+        }
+
+        /// Apply change now if `ChangeDelay` is zero, otherwise store it to be applied later
+        fn announce(actor: Option<T::AccountId>, change: Change<T, I>) -> DispatchResult {
+            let delay = T::ChangeDelay::get();
+            if delay.is_zero() {
+                return Self::apply_change(actor, change);
+            }
+            Self::ensure_change_target(&change)?;
+            ensure!(
+                Self::change_fits_block(Self::change_weight(&change)),
+                Error::<T, I>::ChangeTooHeavy
+            );
+            let at = frame_system::Pallet::<T>::block_number().saturating_add(delay);
+            if let Change::Grant(id, who, role, expiry) = &change {
+                let payer = Self::deposit_payer(actor.clone());
+                Self::ensure_can_grant(*id, who, *role, *expiry, at, &payer)?;
+            }
+            let id = NextChangeId::<T, I>::mutate(|next| {
+                let id = *next;
+                *next = next.saturating_add(1);
+                id
+            });
+            PendingChanges::<T, I>::insert(id, (at, actor, change.clone()));
+            Self::deposit_event(Event::ChangeAnnounced(id, change, at));
+            Ok(())
+        }
+
+        /// Fail unless the change only removes permissions or its id exists
+        fn ensure_change_target(change: &Change<T, I>) -> DispatchResult {
+            match change {
+                Change::Revoke(..) | Change::RemoveCalls(..) => Ok(()),
+                Change::Grant(id, ..)
+                | Change::SetCalls(id, _)
+                | Change::AddCalls(id, _)
+                | Change::SetCallConstraints(id, _)
+                | Change::SetCallIndices(id, _)
+                | Change::SetFeePolicy(id, _)
+                | Change::SetQuota(id, ..)
+                | Change::ResetUsage(id, _)
+                | Change::SetApprovalRequired(id, ..) => Self::ensure_role_exists(*id),
+            }
+        }
+
+        /// Fail if granting role to who for id at block `at` would fail: who holds a grant
+        /// which has not expired by then, who is Executer for `MaxRolesPerAccount` ids, the
+        /// expiry is not after `at` or payer cannot hold the grant deposit
+        fn ensure_can_grant(
+            id: RoleId,
+            who: &T::AccountId,
+            role: Role,
+            expiry: Option<BlockNumberFor<T>>,
+            at: BlockNumberFor<T>,
+            payer: &T::AccountId,
+        ) -> DispatchResult {
+            if let Some(expiry) = expiry {
+                ensure!(expiry > at, Error::<T, I>::ExpiryInPast);
+            }
+            let mut count = PermissionCount::<T, I>::get(who);
+            if let Some(grant) = Roles::<T, I>::get(id, who) {
+                ensure!(
+                    grant.expiry.map_or(false, |expiry| expiry <= at),
+                    Error::<T, I>::AlreadyGrantedAccess
+                );
+                if matches!(grant.role, Role::Executer) {
+                    count = count.saturating_sub(1);
+                }
+            }
+            if matches!(role, Role::Executer) {
+                ensure!(
+                    count < T::MaxRolesPerAccount::get(),
+                    Error::<T, I>::TooManyRoles
+                );
+            }
+            let deposit = T::GrantDeposit::get();
+            if !deposit.is_zero() {
+                T::Currency::ensure_can_hold(&HoldReason::<I>::Grant.into(), payer, deposit)?;
+            }
+            Ok(())
+        }
+
+        /// Fail unless id was created and is not being destroyed
+        pub(crate) fn ensure_role_exists(id: RoleId) -> DispatchResult {
+            let info = RoleInfos::<T, I>::get(id).ok_or(Error::<T, I>::RoleDNE)?;
+            ensure!(!info.destroying, Error::<T, I>::RoleDestroying);
+            Ok(())
+        }
+
+        /// Fail unless origin is the `SuperUser` or signed by the owner in info
+        fn ensure_owner(origin: OriginFor<T>, info: &RoleInfoOf<T, I>) -> DispatchResult {
+            if T::SuperUser::ensure_origin(origin.clone()).is_err() {
+                let who = ensure_signed(origin)?;
+                ensure!(who == info.owner, Error::<T, I>::CallerNotOwner);
+            }
+            Ok(())
+        }
+
+        /// Register a role with the next id and return the id
+        pub(crate) fn do_create_role(
+            owner: T::AccountId,
+            metadata: BoundedVec<u8, T::MaxMetadataLen>,
+        ) -> RoleId {
+            let id = NextRoleId::<T, I>::mutate(|next| {
+                let id = *next;
+                *next = next.saturating_add(1);
+                id
+            });
+            RoleInfos::<T, I>::insert(
+                id,
+                RoleInfo {
+                    owner,
+                    metadata,
+                    destroying: false,
+                },
+            );
+            id
+        }
+
+        /// Remove up to limit grants, calls, proposals, quotas and usages of id.
+        /// Return the number of entries removed.
+        fn destroy_entries(id: RoleId, limit: u32) -> u32 {
+            let mut removed = 0u32;
+            let grants = Roles::<T, I>::iter_prefix(id)
+                .take(limit as usize)
+                .collect::<Vec<_>>();
+            for (who, grant) in grants {
+                Self::do_revoke(id, &who, grant);
+                removed += 1;
+            }
+            let remaining = limit.saturating_sub(removed) as usize;
+            for (hash, _) in CallOrigins::<T, I>::drain_prefix(id).take(remaining) {
+                Self::unnote_call(hash);
+                Self::release_call_deposit(id, hash);
+                CallCount::<T, I>::mutate(id, |count| *count = count.saturating_sub(1));
+                removed += 1;
+            }
+            let remaining = limit.saturating_sub(removed) as usize;
+            for (hash, _) in Proposals::<T, I>::drain_prefix(id).take(remaining) {
+                let _ = Approvals::<T, I>::clear_prefix((id, hash), u32::MAX, None);
+                removed += 1;
+            }
+            let remaining = limit.saturating_sub(removed) as usize;
+            removed += ApprovalRequired::<T, I>::drain_prefix(id)
+                .take(remaining)
+                .count() as u32;
+            let remaining = limit.saturating_sub(removed) as usize;
+            removed += Quotas::<T, I>::drain_prefix(id).take(remaining).count() as u32;
+            let remaining = limit.saturating_sub(removed) as usize;
+            removed += Usages::<T, I>::drain_prefix(id).take(remaining).count() as u32;
+            removed
+        }
+
+        /// Remove the remaining storage of id, which is bounded by `MaxCalls`,
+        /// and refund the role account to the owner
+        fn finish_destroy(id: RoleId, owner: &T::AccountId) {
+            let _ = CallConstraints::<T, I>::clear_prefix(id, T::MaxCalls::get(), None);
+            let _ = CallIndexOrigins::<T, I>::clear_prefix(id, T::MaxCalls::get(), None);
+            CallCount::<T, I>::remove(id);
+            FeePolicies::<T, I>::remove(id);
+            PausedRoles::<T, I>::remove(id);
+            AuditLog::<T, I>::remove(id);
+            Metadata::<T, I>::remove(id);
+            RoleInfos::<T, I>::remove(id);
+            let account = Self::role_account(id);
+            let balance = T::Currency::reducible_balance(
+                &account,
+                Preservation::Expendable,
+                Fortitude::Polite,
+            );
+            if !balance.is_zero() {
+                // The role account keeps its funds if the owner cannot receive them
+                let _ = T::Currency::transfer(&account, owner, balance, Preservation::Expendable);
+            }
+        }
+
+        /// Return true iff `on_idle` of an otherwise empty block can apply a change of
+        /// change_weight, bounded like a normal extrinsic
+        fn change_fits_block(change_weight: Weight) -> bool {
+            let weights = T::BlockWeights::get();
+            let max = weights
+                .get(DispatchClass::Normal)
+                .max_extrinsic
+                .unwrap_or(weights.max_block);
+            max.all_gte(change_weight.saturating_add(T::DbWeight::get().reads_writes(3, 3)))
+        }
+
+        /// Weight of applying change
+        fn change_weight(change: &Change<T, I>) -> Weight {
+            match change {
+                Change::Grant(_, _, _, None) => T::WeightInfo::grant_access(),
+                Change::Grant(_, _, _, Some(_)) => T::WeightInfo::grant_access_until(),
+                Change::Revoke(..) => T::WeightInfo::revoke_access(),
+                Change::SetCalls(_, calls) => T::WeightInfo::set_calls(calls.len() as u32),
+                Change::AddCalls(_, calls) => T::WeightInfo::add_calls(calls.len() as u32),
+                Change::RemoveCalls(_, hashes) => T::WeightInfo::remove_calls(hashes.len() as u32),
+                Change::SetCallConstraints(_, constraints) => {
+                    T::WeightInfo::set_call_constraints(constraints.len() as u32)
+                }
+                Change::SetCallIndices(_, indices) => {
+                    T::WeightInfo::set_call_indices(indices.len() as u32)
+                }
+                Change::SetFeePolicy(..) => T::WeightInfo::set_fee_policy(),
+                Change::SetQuota(..) => T::WeightInfo::set_quota(),
+                Change::ResetUsage(..) => T::WeightInfo::reset_usage(),
+                Change::SetApprovalRequired(..) => T::WeightInfo::set_approval_required(),
+            }
+        }
+
+        /// Apply permission or role change, emit its event and record it in the audit log
+        pub(crate) fn apply_change(
+            actor: Option<T::AccountId>,
+            change: Change<T, I>,
+        ) -> DispatchResult {
+            Self::ensure_change_target(&change)?;
+            let (id, action) = match &change {
+                Change::Grant(id, who, role, _) => (*id, AuditAction::Granted(who.clone(), *role)),
+                Change::Revoke(id, who) => {
+                    let grant = Roles::<T, I>::get(id, who).ok_or(Error::<T, I>::AccessDNE)?;
+                    (*id, AuditAction::Revoked(who.clone(), grant.role))
+                }
+                Change::SetCalls(id, _)
+                | Change::AddCalls(id, _)
+                | Change::RemoveCalls(id, _)
+                | Change::SetCallConstraints(id, _)
+                | Change::SetCallIndices(id, _) => (*id, AuditAction::CallsChanged),
+                Change::SetFeePolicy(id, _)
+                | Change::SetQuota(id, ..)
+                | Change::ResetUsage(id, _)
+                | Change::SetApprovalRequired(id, ..) => (*id, AuditAction::SettingsChanged),
+            };
+            match change {
+                Change::Grant(id, who, role, expiry) => {
+                    if let Some(expiry) = expiry {
+                        ensure!(
+                            expiry > frame_system::Pallet::<T>::block_number(),
+                            Error::<T, I>::ExpiryInPast
+                        );
+                    }
+                    Self::do_grant(id, &who, role, expiry)?;
+                    Self::hold_grant_deposit(id, &who, Self::deposit_payer(actor.clone()))?;
+                    Self::deposit_event(match expiry {
+                        Some(expiry) => Event::AccessGrantedUntil(id, who, role, expiry),
+                        None => Event::AccessGranted(id, who, role),
+                    });
+                }
+                Change::Revoke(id, who) => {
+                    let grant = Roles::<T, I>::get(id, &who).ok_or(Error::<T, I>::AccessDNE)?;
+                    Self::do_revoke(id, &who, grant);
+                    Self::deposit_event(Event::AccessRevoked(id, who, grant.role));
+                }
+                Change::SetCalls(id, calls) => {
+                    for (hash, _) in CallOrigins::<T, I>::drain_prefix(id) {
+                        Self::unnote_call(hash);
+                        Self::release_call_deposit(id, hash);
+                    }
+                    CallCount::<T, I>::remove(id);
+                    let payer = Self::deposit_payer(actor.clone());
+                    Self::do_add_calls(id, calls.into_inner(), Some(&payer))?;
+                    Self::deposit_event(Event::CallsUpdated(id));
+                }
+                Change::AddCalls(id, calls) => {
+                    let payer = Self::deposit_payer(actor.clone());
+                    let hashes = Self::do_add_calls(id, calls.into_inner(), Some(&payer))?;
+                    Self::deposit_event(Event::CallsAdded(id, hashes));
+                }
+                Change::RemoveCalls(id, hashes) => {
+                    for hash in hashes.iter() {
+                        ensure!(
+                            CallOrigins::<T, I>::take(id, hash).is_some(),
+                            Error::<T, I>::CallOriginNotSet
+                        );
+                        Self::unnote_call(*hash);
+                        Self::release_call_deposit(id, *hash);
+                        CallCount::<T, I>::mutate(id, |count| *count = count.saturating_sub(1));
+                    }
+                    Self::deposit_event(Event::CallsRemoved(id, hashes.into_inner()));
+                }
+                Change::SetCallConstraints(id, constraints) => {
+                    let _ = CallConstraints::<T, I>::clear_prefix(id, T::MaxCalls::get(), None);
+                    for CallOrigin { call, origin } in constraints.into_iter() {
+                        CallConstraints::<T, I>::insert(id, call, origin);
+                    }
+                    Self::deposit_event(Event::CallConstraintsUpdated(id));
+                }
+                Change::SetCallIndices(id, indices) => {
+                    let _ = CallIndexOrigins::<T, I>::clear_prefix(id, T::MaxCalls::get(), None);
+                    for CallOrigin { call, origin } in indices.into_iter() {
+                        CallIndexOrigins::<T, I>::insert(id, call, origin);
+                    }
+                    Self::deposit_event(Event::CallIndicesUpdated(id));
+                }
+                Change::SetFeePolicy(id, policy) => {
+                    FeePolicies::<T, I>::insert(id, policy);
+                    Self::deposit_event(Event::FeePolicyUpdated(id, policy));
+                }
+                Change::SetQuota(id, target, quota) => {
+                    match quota {
+                        Some(quota) => Quotas::<T, I>::insert(id, &target, quota),
+                        None => Quotas::<T, I>::remove(id, &target),
+                    }
+                    Self::deposit_event(Event::QuotaUpdated(id, target, quota));
+                }
+                Change::ResetUsage(id, target) => {
+                    Usages::<T, I>::remove(id, &target);
+                    Self::deposit_event(Event::UsageReset(id, target));
+                }
+                Change::SetApprovalRequired(id, call_hash, required) => {
+                    if required {
+                        ApprovalRequired::<T, I>::insert(id, call_hash, ());
+                    } else {
+                        ApprovalRequired::<T, I>::remove(id, call_hash);
+                    }
+                    Self::deposit_event(Event::ApprovalRequirementUpdated(id, call_hash, required));
+                }
+            }
+            Self::audit(id, actor, action);
+            Ok(())
+        }
+
+        /// Append action to the audit log of id, pruning the oldest entry if full
+        pub(crate) fn audit(id: RoleId, actor: Option<T::AccountId>, action: AuditActionOf<T>) {
+            if T::AuditLogDepth::get() == 0 {
+                return;
+            }
+            AuditLog::<T, I>::mutate(id, |log| {
+                if log.is_full() {
+                    log.remove(0);
+                }
+                let _ = log.try_push(AuditEntry {
+                    actor,
+                    block: frame_system::Pallet::<T>::block_number(),
+                    action,
+                });
+            });
+        }
+
+        /// Apply announced changes due at or before now in order, within remaining weight.
+        /// Returns the consumed weight.
+        pub(crate) fn apply_changes(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let mut used = db.reads(2);
+            let mut cursor = ChangeCursor::<T, I>::get();
+            let start = cursor;
+            let next = NextChangeId::<T, I>::get();
+            let step = db.reads_writes(1, 2);
+            while cursor < next && remaining_weight.all_gte(used.saturating_add(step)) {
+                let Some((at, actor, change)) = PendingChanges::<T, I>::get(cursor) else {
+                    // cancelled
+                    used = used.saturating_add(step);
+                    cursor = cursor.saturating_add(1);
+                    continue;
+                };
+                let change_weight = Self::change_weight(&change);
+                if at <= now && !Self::change_fits_block(change_weight) {
+                    // fail a change which would never fit instead of blocking later changes
+                    used = used.saturating_add(step);
+                    PendingChanges::<T, I>::remove(cursor);
+                    Self::deposit_event(Event::ChangeApplied(
+                        cursor,
+                        Err(Error::<T, I>::ChangeTooHeavy.into()),
+                    ));
+                    cursor = cursor.saturating_add(1);
+                    continue;
+                }
+                if at > now
+                    || !remaining_weight
+                        .all_gte(used.saturating_add(step).saturating_add(change_weight))
+                {
+                    used = used.saturating_add(db.reads(1));
+                    break;
+                }
+                used = used.saturating_add(step).saturating_add(change_weight);
+                PendingChanges::<T, I>::remove(cursor);
+                let result = with_storage_layer(|| Self::apply_change(actor, change));
+                Self::deposit_event(Event::ChangeApplied(cursor, result));
+                cursor = cursor.saturating_add(1);
+            }
+            if cursor != start {
+                ChangeCursor::<T, I>::put(cursor);
+            }
+            used
+        }
+
+        /// Remove proposal for id and call hash with its approvals
+        fn remove_proposal(id: RoleId, call_hash: CallHashOf<T>) {
+            Proposals::<T, I>::remove(id, call_hash);
+            let _ = Approvals::<T, I>::clear_prefix((id, call_hash), u32::MAX, None);
+        }
+
+        /// Count one execution against the quota of target for id.
+        /// Fails if the execution exceeds the quota.
+        fn use_quota(id: RoleId, target: QuotaTargetOf<T>) -> DispatchResult {
+            let Some(quota) = Quotas::<T, I>::get(id, &target) else {
+                return Ok(());
+            };
+            let now = frame_system::Pallet::<T>::block_number();
+            Usages::<T, I>::try_mutate(id, &target, |usage| {
+                if let Some((max, period)) = quota.per_period {
+                    if usage.in_period == 0 || now >= usage.period_start.saturating_add(period) {
+                        usage.period_start = now;
+                        usage.in_period = 0;
+                    }
+                    ensure!(usage.in_period < max, Error::<T, I>::QuotaExceeded);
+                    usage.in_period = usage.in_period.saturating_add(1);
+                }
+                if let Some(max) = quota.total {
+                    ensure!(usage.total < max, Error::<T, I>::QuotaExceeded);
+                }
+                usage.total = usage.total.saturating_add(1);
+                Ok(())
+            })
+        }
+
+        /// Return true iff grant expired at or before the current block
+        fn is_expired(grant: &GrantOf<T>) -> bool {
+            grant.expiry.map_or(false, |expiry| {
+                expiry <= frame_system::Pallet::<T>::block_number()
+            })
+        }
+
+        /// Remove grants in ExpiryQueue from ExpiryCursor up to now, within remaining weight.
+        /// Returns the consumed weight.
+        pub(crate) fn expire_grants(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let db = T::DbWeight::get();
+            let mut used = db.reads(1);
+            let Some(mut cursor) = ExpiryCursor::<T, I>::get() else {
+                return used;
+            };
+            let start = cursor;
+            let cursor_step = db.reads_writes(1, 1);
+            let grant_step = T::WeightInfo::expire_grant();
+            'blocks: while cursor <= now {
+                let mut expiring = ExpiryQueue::<T, I>::drain_prefix(cursor);
+                loop {
+                    let step = used.saturating_add(grant_step).saturating_add(cursor_step);
+                    if !remaining_weight.all_gte(step) {
+                        break 'blocks;
+                    }
+                    let Some(((id, who), ())) = expiring.next() else {
+                        break;
+                    };
+                    used = used.saturating_add(grant_step);
+                    match Roles::<T, I>::get(id, &who) {
+                        Some(grant) if grant.expiry == Some(cursor) => {
+                            Self::do_revoke(id, &who, grant);
+                            Self::audit(id, None, AuditAction::Expired(who.clone(), grant.role));
+                            Self::deposit_event(Event::AccessExpired(id, who, grant.role));
+                        }
+                        _ => (),
+                    }
+                }
+                used = used.saturating_add(cursor_step);
+                cursor = cursor.saturating_add(One::one());
+            }
+            if cursor != start {
+                ExpiryCursor::<T, I>::put(cursor);
+            }
+            used
+        }
+
+        /// Return dispatch origin iff call is permitted for id.
+        /// Precedence: exact call, call index, pallet index, call constraints.
+        fn call_origin(
+            id: RoleId,
+            call: &<T as Config<I>>::RuntimeCall,
+            hash: CallHashOf<T>,
+        ) -> Option<<T as Config<I>>::PalletsOrigin> {
+            if let Some(origin) = CallOrigins::<T, I>::get(id, hash) {
+                return Some(origin);
+            }
+            if let Ok((pallet_index, call_index)) = Self::call_index(call) {
+                if let Some(origin) =
+                    CallIndexOrigins::<T, I>::get(id, CallIndex::Call(pallet_index, call_index))
+                {
+                    return Some(origin);
+                }
+                if let Some(origin) =
+                    CallIndexOrigins::<T, I>::get(id, CallIndex::Pallet(pallet_index))
+                {
+                    return Some(origin);
+                }
+            }
+            CallConstraints::<T, I>::iter_prefix(id)
+                .find(|(constraint, _)| constraint.matches(call))
+                .map(|(_, origin)| origin)
+        }
+
+        /// Insert calls into CallOrigins for id and return their hashes, holding the call
+        /// deposit for each new entry from payer if any.
+        /// Fails if the number of calls for id would exceed `MaxCalls`.
+        pub(crate) fn do_add_calls(
+            id: RoleId,
+            calls: Vec<(EncodedCallOf<T, I>, <T as Config<I>>::PalletsOrigin)>,
+            payer: Option<&T::AccountId>,
+        ) -> Result<Vec<CallHashOf<T>>, DispatchError> {
+            let mut count = CallCount::<T, I>::get(id);
+            let mut hashes = Vec::with_capacity(calls.len());
+            for (call, origin) in calls.into_iter() {
+                let hash = T::Hashing::hash(&call);
+                if !CallOrigins::<T, I>::contains_key(id, hash) {
+                    count = count.saturating_add(1);
+                    if let Some(payer) = payer {
+                        Self::hold_call_deposit(id, hash, call.len() as u32, payer.clone())?;
+                    }
+                    Self::note_call(hash, call);
+                }
+                CallOrigins::<T, I>::insert(id, hash, origin);
+                hashes.push(hash);
+            }
+            ensure!(count <= T::MaxCalls::get(), Error::<T, I>::TooManyCalls);
+            CallCount::<T, I>::insert(id, count);
+            Ok(hashes)
+        }
+
+        /// Store call in `Calls` or increment its reference count if already stored
+        pub(crate) fn note_call(hash: CallHashOf<T>, call: EncodedCallOf<T, I>) {
+            Calls::<T, I>::mutate(hash, |maybe_call| match maybe_call {
+                Some((_, refs)) => *refs = refs.saturating_add(1),
+                None => *maybe_call = Some((call, 1)),
+            });
+        }
+
+        /// Decrement call reference count and remove it from `Calls` once unreferenced
+        fn unnote_call(hash: CallHashOf<T>) {
+            Calls::<T, I>::mutate_exists(hash, |maybe_call| {
+                if let Some((_, refs)) = maybe_call {
+                    *refs = refs.saturating_sub(1);
+                    if *refs == 0 {
+                        *maybe_call = None;
+                    }
+                }
+            });
+        }
+
+        /// Signing account of a change, otherwise the `DepositPayer`
+        pub(crate) fn deposit_payer(actor: Option<T::AccountId>) -> T::AccountId {
+            actor.unwrap_or_else(T::DepositPayer::get)
+        }
+
+        /// Hold `GrantDeposit` from payer for the grant of who for id
+        pub(crate) fn hold_grant_deposit(
+            id: RoleId,
+            who: &T::AccountId,
+            payer: T::AccountId,
+        ) -> DispatchResult {
+            let deposit = T::GrantDeposit::get();
+            if deposit.is_zero() {
+                return Ok(());
+            }
+            T::Currency::hold(&HoldReason::<I>::Grant.into(), &payer, deposit)?;
+            GrantDeposits::<T, I>::insert(id, who, (payer, deposit));
+            Ok(())
+        }
+
+        /// Release the deposit for the grant of who for id, if any
+        fn release_grant_deposit(id: RoleId, who: &T::AccountId) {
+            if let Some((payer, deposit)) = GrantDeposits::<T, I>::take(id, who) {
+                let _ = T::Currency::release(
+                    &HoldReason::<I>::Grant.into(),
+                    &payer,
+                    deposit,
+                    Precision::BestEffort,
+                );
+            }
+        }
+
+        /// Hold `CallDepositPerByte` for each of len bytes from payer for the call with hash
+        fn hold_call_deposit(
+            id: RoleId,
+            hash: CallHashOf<T>,
+            len: u32,
+            payer: T::AccountId,
+        ) -> DispatchResult {
+            let deposit = T::CallDepositPerByte::get().saturating_mul(len.into());
+            if deposit.is_zero() {
+                return Ok(());
+            }
+            T::Currency::hold(&HoldReason::<I>::Call.into(), &payer, deposit)?;
+            CallDeposits::<T, I>::insert(id, hash, (payer, deposit));
+            Ok(())
+        }
+
+        /// Release the deposit for the call with hash for id, if any
+        fn release_call_deposit(id: RoleId, hash: CallHashOf<T>) {
+            if let Some((payer, deposit)) = CallDeposits::<T, I>::take(id, hash) {
+                let _ = T::Currency::release(
+                    &HoldReason::<I>::Call.into(),
+                    &payer,
+                    deposit,
+                    Precision::BestEffort,
+                );
+            }
+        }
+
+        /// Pays the fee for `weight` from the id account according to the id fee policy.
+        /// Returns whether the executer still pays the transaction fee.
+        fn pay_fee(id: RoleId, who: &T::AccountId, weight: Weight) -> Result<Pays, DispatchError> {
+            let policy = FeePolicies::<T, I>::get(id);
+            if matches!(policy, FeePolicy::Executer) {
+                return Ok(Pays::Yes);
+            }
+            let fee = T::WeightToFee::weight_to_fee(&weight);
+            if fee.is_zero() {
+                return Ok(if matches!(policy, FeePolicy::Free) {
+                    Pays::No
+                } else {
+                    Pays::Yes
+                });
+            }
+            let account = Self::role_account(id);
+            ensure!(
+                T::Currency::reducible_balance(&account, Preservation::Preserve, Fortitude::Polite)
+                    >= fee,
+                Error::<T, I>::InsufficientRoleFunds
+            );
+            match policy {
+                FeePolicy::Free => {
+                    T::Currency::burn_from(&account, fee, Precision::Exact, Fortitude::Polite)?;
+                    Ok(Pays::No)
+                }
+                _ => {
+                    T::Currency::transfer(&account, who, fee, Preservation::Preserve)?;
+                    Ok(Pays::Yes)
+                }
+            }
+        }
     }
+    #[cfg(any(feature = "try-runtime", test))]
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Check storage invariants:
+        /// - every `Permissions` entry has an Executer grant in `Roles` and vice versa
+        /// - `PermissionCount` counts `Permissions` and never exceeds `MaxRolesPerAccount`
+        /// - `CallCount` counts `CallOrigins` and no id has more than `MaxCalls` calls,
+        ///   call indices or call constraints
+        /// - grants, calls and metadata belong to ids in `RoleInfos`, which are below
+        ///   `NextRoleId`
+        /// - every `GrantDeposits` entry has a grant and every `CallDeposits` entry a call
+        pub(crate) fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            let next_id = NextRoleId::<T, I>::get();
+            for id in RoleInfos::<T, I>::iter_keys() {
+                ensure!(id < next_id, "RoleInfos entry not below NextRoleId");
+            }
+            let ids = Roles::<T, I>::iter_keys()
+                .map(|(id, _)| id)
+                .chain(CallOrigins::<T, I>::iter_keys().map(|(id, _)| id))
+                .chain(Metadata::<T, I>::iter_keys());
+            for id in ids {
+                ensure!(
+                    RoleInfos::<T, I>::contains_key(id),
+                    "grant, call or metadata for id without RoleInfos entry"
+                );
+            }
+            for (id, who) in GrantDeposits::<T, I>::iter_keys() {
+                ensure!(
+                    Roles::<T, I>::contains_key(id, &who),
+                    "GrantDeposits entry without grant"
+                );
+            }
+            for (id, hash) in CallDeposits::<T, I>::iter_keys() {
+                ensure!(
+                    CallOrigins::<T, I>::contains_key(id, hash),
+                    "CallDeposits entry without CallOrigins entry"
+                );
+            }
+            for (who, id, ()) in Permissions::<T, I>::iter() {
+                ensure!(
+                    Roles::<T, I>::get(id, &who).map(|grant| grant.role) == Some(Role::Executer),
+                    "Permissions entry without Executer grant"
+                );
+            }
+            for (id, who, grant) in Roles::<T, I>::iter() {
+                ensure!(
+                    (grant.role == Role::Executer) == Permissions::<T, I>::contains_key(&who, id),
+                    "Executer grant without Permissions entry"
+                );
+            }
+            let accounts = PermissionCount::<T, I>::iter_keys()
+                .chain(Permissions::<T, I>::iter_keys().map(|(who, _)| who));
+            for who in accounts {
+                let count = Permissions::<T, I>::iter_prefix(&who).count() as u32;
+                ensure!(
+                    PermissionCount::<T, I>::get(&who) == count,
+                    "PermissionCount mismatch"
+                );
+                ensure!(
+                    count <= T::MaxRolesPerAccount::get(),
+                    "PermissionCount exceeds MaxRolesPerAccount"
+                );
+            }
+            let max_calls = T::MaxCalls::get() as usize;
+            let ids = CallCount::<T, I>::iter_keys()
+                .chain(CallOrigins::<T, I>::iter_keys().map(|(id, _)| id));
+            for id in ids {
+                let calls = CallOrigins::<T, I>::iter_prefix(id).count();
+                ensure!(
+                    CallCount::<T, I>::get(id) as usize == calls,
+                    "CallCount mismatch"
+                );
+                ensure!(calls <= max_calls, "calls exceed MaxCalls");
+            }
+            for id in CallIndexOrigins::<T, I>::iter_keys().map(|(id, _)| id) {
+                ensure!(
+                    CallIndexOrigins::<T, I>::iter_prefix(id).count() <= max_calls,
+                    "call indices exceed MaxCalls"
+                );
+            }
+            for id in CallConstraints::<T, I>::iter_keys().map(|(id, _)| id) {
+                ensure!(
+                    CallConstraints::<T, I>::iter_prefix(id).count() <= max_calls,
+                    "call constraints exceed MaxCalls"
+                );
+            }
+            Ok(())
+        }
+    }
+
     // Public functions (i.e. RuntimeAPI)
-    impl<T: Config> Pallet<T> {
-        /// Return allowed calls for input account
-        pub fn get_allowed_calls(who: &T::AccountId) -> Vec<<T as Config>::RuntimeCall> {
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Return owner, metadata and status of input id
+        pub fn role_info(id: RoleId) -> Option<RoleInfoOf<T, I>> {
+            RoleInfos::<T, I>::get(id)
+        }
+
+        /// Return owner, opaque and human readable metadata, members and exact calls of input id
+        pub fn role_details(id: RoleId) -> Option<RoleDetailsOf<T, I>> {
+            let info = RoleInfos::<T, I>::get(id)?;
+            let metadata = Metadata::<T, I>::get(id).map(|metadata| RoleMetadata {
+                name: metadata.name.into_inner(),
+                description: metadata.description.into_inner(),
+                tags: metadata
+                    .tags
+                    .into_iter()
+                    .map(|tag| tag.into_inner())
+                    .collect(),
+            });
+            let calls = CallOrigins::<T, I>::iter_prefix(id)
+                .filter_map(|(hash, origin)| Some((Self::stored_call(hash)?, origin)))
+                .collect();
+            Some(RoleDetails {
+                owner: info.owner,
+                opaque_metadata: info.metadata.into_inner(),
+                metadata,
+                members: Self::role_members(id),
+                calls,
+            })
+        }
+
+        /// Return the most recent privileged actions for input id, oldest first
+        pub fn audit_log(id: RoleId) -> Vec<AuditEntryOf<T>> {
+            AuditLog::<T, I>::get(id).into_inner()
+        }
+
+        /// Return true iff execution is paused for input id
+        pub fn is_paused(id: RoleId) -> bool {
+            PausedAll::<T, I>::get() || PausedRoles::<T, I>::contains_key(id)
+        }
+
+        /// Return role of input account for input id unless the grant expired
+        pub fn role(id: RoleId, who: &T::AccountId) -> Option<Role> {
+            Roles::<T, I>::get(id, who)
+                .filter(|grant| !Self::is_expired(grant))
+                .map(|grant| grant.role)
+        }
+
+        /// Return the account funding fees for Executers of input id
+        pub fn role_account(id: RoleId) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(id)
+        }
+
+        /// Return (pallet index, call index) of input call
+        pub fn call_index(call: &<T as Config<I>>::RuntimeCall) -> Result<(u8, u8), DispatchError> {
+            call.using_encoded(|encoded| match encoded {
+                [pallet_index, call_index, ..] => Ok((*pallet_index, *call_index)),
+                _ => Err(Error::<T, I>::EncodingFailed.into()),
+            })
+        }
+
+        /// Return input call encoded and bounded by `MaxCallLen`
+        pub fn encode_call(
+            call: &<T as Config<I>>::RuntimeCall,
+        ) -> Result<EncodedCallOf<T, I>, DispatchError> {
+            EncodedCallOf::<T, I>::try_from(call.encode())
+                .map_err(|_| Error::<T, I>::CallTooLong.into())
+        }
+
+        /// Return call stored for input hash
+        pub fn stored_call(hash: CallHashOf<T>) -> Option<<T as Config<I>>::RuntimeCall> {
+            let (encoded, _) = Calls::<T, I>::get(hash)?;
+            <T as Config<I>>::RuntimeCall::decode(&mut &encoded[..]).ok()
+        }
+
+        /// Return allowed exact calls for input account
+        pub fn get_allowed_calls(who: &T::AccountId) -> Vec<<T as Config<I>>::RuntimeCall> {
+            Self::allowed_calls(who)
+                .into_iter()
+                .map(|(_, call, _)| call)
+                .collect()
+        }
+
+        /// Return (id, call, dispatch origin) for each exact call allowed for input account
+        pub fn allowed_calls(
+            who: &T::AccountId,
+        ) -> Vec<(
+            RoleId,
+            <T as Config<I>>::RuntimeCall,
+            <T as Config<I>>::PalletsOrigin,
+        )> {
             let mut allowed_calls = Vec::new();
-            for (id, _) in Permissions::<T>::iter_prefix(&who) {
-                for (call, _) in CallOrigins::<T>::iter_prefix(id) {
-                    allowed_calls.push(call);
+            for (id, _) in Permissions::<T, I>::iter_prefix(who) {
+                if Self::role(id, who).is_none() {
+                    continue;
+                }
+                for (hash, origin) in CallOrigins::<T, I>::iter_prefix(id) {
+                    if let Some(call) = Self::stored_call(hash) {
+                        allowed_calls.push((id, call, origin));
+                    }
                 }
             }
             allowed_calls
         }
+
+        /// Return (account, role) for each member of input id
+        pub fn role_members(id: RoleId) -> Vec<(T::AccountId, Role)> {
+            Roles::<T, I>::iter_prefix(id)
+                .filter(|(_, grant)| !Self::is_expired(grant))
+                .map(|(who, grant)| (who, grant.role))
+                .collect()
+        }
+
+        /// Return (id, role) for each role of input account.
+        /// Iterates over all roles so it is meant for off-chain queries.
+        pub fn account_roles(who: &T::AccountId) -> Vec<(RoleId, Role)> {
+            Roles::<T, I>::iter()
+                .filter(|(_, account, grant)| account == who && !Self::is_expired(grant))
+                .map(|(id, _, grant)| (id, grant.role))
+                .collect()
+        }
+
+        /// Return (id, dispatch origin) iff input account is permitted to make the
+        /// call due to membership as an Executer with the permitted ID.
+        /// Ids which are paused or require approval for the call are skipped.
+        pub fn can_execute(
+            who: &T::AccountId,
+            call: &<T as Config<I>>::RuntimeCall,
+        ) -> Option<(RoleId, <T as Config<I>>::PalletsOrigin)> {
+            let hash = T::Hashing::hash_of(call);
+            for (id, _) in Permissions::<T, I>::iter_prefix(who) {
+                if Self::role(id, who).is_none()
+                    || Self::is_paused(id)
+                    || ApprovalRequired::<T, I>::contains_key(id, hash)
+                {
+                    continue;
+                }
+                if let Some(origin) = Self::call_origin(id, call, hash) {
+                    return Some((id, origin));
+                }
+            }
+            None
+        }
     }
 }
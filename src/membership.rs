@@ -0,0 +1,100 @@
+//! Call-RBAC membership adapters for pallets which take a set of members.
+use super::*;
+use frame_support::{
+    storage::with_storage_layer,
+    traits::{ChangeMembers, Contains, InitializeMembers, SortedMembers},
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Accounts with role `R` for id `Id`, as a membership source or sink.
+///
+/// `ChangeMembers` and `InitializeMembers` grant and revoke the role, so another
+/// membership pallet may keep the role up to date.
+pub struct RoleMembers<T, Id, R, I = ()>(PhantomData<(T, Id, R, I)>);
+
+impl<T: Config<I>, I: 'static, Id: Get<RoleId>, R: Get<Role>> RoleMembers<T, Id, R, I> {
+    fn grant(who: &T::AccountId) {
+        let (id, role) = (Id::get(), R::get());
+        // do_grant may revoke an expired grant before failing, so roll it back on error
+        match with_storage_layer(|| {
+            Pallet::<T, I>::ensure_role_exists(id)?;
+            Pallet::<T, I>::do_grant(id, who, role, None)
+        }) {
+            Ok(()) => {
+                Pallet::<T, I>::audit(id, None, AuditAction::Granted(who.clone(), role));
+                Pallet::<T, I>::deposit_event(Event::AccessGranted(id, who.clone(), role))
+            }
+            Err(e) => log::warn!(
+                target: LOG_TARGET,
+                "failed to grant {:?} access for id {} to new member: {:?}",
+                role,
+                id,
+                e
+            ),
+        }
+    }
+
+    fn revoke(who: &T::AccountId) {
+        let id = Id::get();
+        match Roles::<T, I>::get(id, who) {
+            Some(grant) if grant.role == R::get() => {
+                Pallet::<T, I>::do_revoke(id, who, grant);
+                Pallet::<T, I>::audit(id, None, AuditAction::Revoked(who.clone(), grant.role));
+                Pallet::<T, I>::deposit_event(Event::AccessRevoked(id, who.clone(), grant.role));
+            }
+            _ => (),
+        }
+    }
+}
+
+impl<T: Config<I>, I: 'static, Id: Get<RoleId>, R: Get<Role>> Contains<T::AccountId>
+    for RoleMembers<T, Id, R, I>
+{
+    fn contains(who: &T::AccountId) -> bool {
+        Pallet::<T, I>::role(Id::get(), who) == Some(R::get())
+    }
+}
+
+impl<T: Config<I>, I: 'static, Id: Get<RoleId>, R: Get<Role>> SortedMembers<T::AccountId>
+    for RoleMembers<T, Id, R, I>
+{
+    fn sorted_members() -> Vec<T::AccountId> {
+        let mut members = Pallet::<T, I>::role_members(Id::get())
+            .into_iter()
+            .filter(|(_, role)| *role == R::get())
+            .map(|(who, _)| who)
+            .collect::<Vec<_>>();
+        members.sort();
+        members
+    }
+
+    fn contains(who: &T::AccountId) -> bool {
+        <Self as Contains<T::AccountId>>::contains(who)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &T::AccountId) {
+        Self::grant(who)
+    }
+}
+
+impl<T: Config<I>, I: 'static, Id: Get<RoleId>, R: Get<Role>> ChangeMembers<T::AccountId>
+    for RoleMembers<T, Id, R, I>
+{
+    fn change_members_sorted(
+        incoming: &[T::AccountId],
+        outgoing: &[T::AccountId],
+        _sorted_new: &[T::AccountId],
+    ) {
+        outgoing.iter().for_each(Self::revoke);
+        incoming.iter().for_each(Self::grant);
+    }
+}
+
+impl<T: Config<I>, I: 'static, Id: Get<RoleId>, R: Get<Role>> InitializeMembers<T::AccountId>
+    for RoleMembers<T, Id, R, I>
+{
+    fn initialize_members(members: &[T::AccountId]) {
+        members.iter().for_each(Self::grant);
+    }
+}
@@ -0,0 +1,262 @@
+//! Call-RBAC storage migrations.
+//!
+//! Each migration is wrapped in `VersionedMigration`, which only runs it if the on-chain
+//! storage version matches and then bumps the version. Runtimes should include `Migrations`
+//! in their `Executive` migrations to upgrade from any earlier version.
+//!
+//! Multi-block (stepped) migrations are not available at the `frame-support` revision this
+//! pallet builds against. Layout changes too large for a single block should instead drain
+//! the old layout lazily with a cursor in `on_idle`, like `ExpiryQueue` and `PendingChanges`.
+use super::*;
+use frame_support::{migrations::VersionedMigration, traits::OnRuntimeUpgrade};
+use sp_runtime::traits::Hash;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Migrate `CallOrigins` from call keys to call hash keys.
+pub mod v1 {
+    use super::*;
+
+    pub(crate) mod v0 {
+        use super::*;
+
+        /// Id, Call => Option<Origin>
+        ///
+        /// The deployed layout, keyed by the raw call encoding. Keys bounded by `MaxCallLen`
+        /// were never deployed, so this migration upgrades directly from call keys.
+        #[frame_support::storage_alias]
+        pub type CallOrigins<T: Config<I>, I: 'static> = StorageDoubleMap<
+            Pallet<T, I>,
+            Blake2_128Concat,
+            RoleId,
+            Blake2_128Concat,
+            <T as Config<I>>::RuntimeCall,
+            <T as Config<I>>::PalletsOrigin,
+            OptionQuery,
+        >;
+    }
+
+    /// Key `CallOrigins` by call hash and store each call once in `Calls`.
+    /// Calls longer than `MaxCallLen` cannot be stored and are dropped with a warning.
+    /// Does not check the storage version, use `MigrateToV1`.
+    pub struct UncheckedMigrateToV1<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+    /// `UncheckedMigrateToV1` iff the on-chain storage version is 0
+    pub type MigrateToV1<T, I = ()> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateToV1<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for UncheckedMigrateToV1<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let entries = v0::CallOrigins::<T, I>::drain().collect::<sp_std::vec::Vec<_>>();
+            let count = entries.len() as u64;
+            for (id, call, origin) in entries.into_iter() {
+                let Ok(encoded) = Pallet::<T, I>::encode_call(&call) else {
+                    log::warn!(
+                        target: LOG_TARGET,
+                        "MigrateToV1 dropped call of id {} longer than MaxCallLen",
+                        id
+                    );
+                    continue;
+                };
+                let hash = T::Hashing::hash(&encoded);
+                if !CallOrigins::<T, I>::contains_key(id, hash) {
+                    Pallet::<T, I>::note_call(hash, encoded);
+                    CallCount::<T, I>::mutate(id, |count| *count = count.saturating_add(1));
+                }
+                CallOrigins::<T, I>::insert(id, hash, origin);
+            }
+            log::info!(target: LOG_TARGET, "MigrateToV1 migrated {} call origins", count);
+            T::DbWeight::get().reads_writes(count.saturating_mul(3), count.saturating_mul(4))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let count = v0::CallOrigins::<T, I>::iter()
+                .filter(|(_, call, _)| Pallet::<T, I>::encode_call(call).is_ok())
+                .count() as u32;
+            Ok(count.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("failed to decode pre-upgrade state"))?;
+            ensure!(
+                CallOrigins::<T, I>::iter().count() as u32 == count,
+                "call origins count changed"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Migrate `Roles` values from `Role` to `Grant` without expiry and count `Permissions`.
+pub mod v2 {
+    use super::*;
+
+    pub(crate) mod v1 {
+        use super::*;
+
+        /// Id, Account => Option<Role>
+        #[frame_support::storage_alias]
+        pub type Roles<T: Config<I>, I: 'static> = StorageDoubleMap<
+            Pallet<T, I>,
+            Blake2_128Concat,
+            RoleId,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            Role,
+            OptionQuery,
+        >;
+    }
+
+    /// Wrap each `Roles` value in a `Grant` which never expires and fill `PermissionCount`
+    /// from the `Permissions` of each account. Accounts above `MaxRolesPerAccount` keep
+    /// their roles but cannot be granted further Executer roles.
+    /// Does not check the storage version, use `MigrateToV2`.
+    pub struct UncheckedMigrateToV2<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+    /// `UncheckedMigrateToV2` iff the on-chain storage version is 1
+    pub type MigrateToV2<T, I = ()> = VersionedMigration<
+        1,
+        2,
+        UncheckedMigrateToV2<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for UncheckedMigrateToV2<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut count = 0u64;
+            Roles::<T, I>::translate::<Role, _>(|_, _, role| {
+                count = count.saturating_add(1);
+                Some(Grant { role, expiry: None })
+            });
+            let mut permissions = 0u64;
+            for (who, _) in Permissions::<T, I>::iter_keys() {
+                permissions = permissions.saturating_add(1);
+                PermissionCount::<T, I>::mutate(&who, |count| *count = count.saturating_add(1));
+            }
+            log::info!(
+                target: LOG_TARGET,
+                "MigrateToV2 migrated {} roles and counted {} permissions",
+                count,
+                permissions
+            );
+            T::DbWeight::get().reads_writes(
+                count.saturating_add(permissions.saturating_mul(2)),
+                count.saturating_add(permissions),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((v1::Roles::<T, I>::iter().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("failed to decode pre-upgrade state"))?;
+            ensure!(
+                Roles::<T, I>::iter().count() as u32 == count,
+                "roles count changed"
+            );
+            for (who, count) in PermissionCount::<T, I>::iter() {
+                ensure!(
+                    Permissions::<T, I>::iter_prefix(&who).count() as u32 == count,
+                    "PermissionCount mismatch"
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Register every id in use in `RoleInfos`.
+pub mod v3 {
+    use super::*;
+    use sp_std::collections::btree_set::BTreeSet;
+
+    /// Create a `RoleInfo` for each id with grants, calls, call indices, call constraints
+    /// or a fee policy, owned by the role account so that only the `SuperUser` may destroy it.
+    /// Raise `NextRoleId` above the greatest id.
+    /// Does not check the storage version, use `MigrateToV3`.
+    pub struct UncheckedMigrateToV3<T, I = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+    /// `UncheckedMigrateToV3` iff the on-chain storage version is 2
+    pub type MigrateToV3<T, I = ()> = VersionedMigration<
+        2,
+        3,
+        UncheckedMigrateToV3<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    impl<T: Config<I>, I: 'static> UncheckedMigrateToV3<T, I> {
+        fn ids() -> (BTreeSet<RoleId>, u64) {
+            let mut reads = 0u64;
+            let ids = Roles::<T, I>::iter_keys()
+                .map(|(id, _)| id)
+                .chain(CallOrigins::<T, I>::iter_keys().map(|(id, _)| id))
+                .chain(CallIndexOrigins::<T, I>::iter_keys().map(|(id, _)| id))
+                .chain(CallConstraints::<T, I>::iter_keys().map(|(id, _)| id))
+                .chain(FeePolicies::<T, I>::iter_keys())
+                .inspect(|_| reads = reads.saturating_add(1))
+                .collect();
+            (ids, reads)
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for UncheckedMigrateToV3<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let (ids, reads) = Self::ids();
+            let count = ids.len() as u64;
+            for id in ids.iter() {
+                RoleInfos::<T, I>::insert(
+                    id,
+                    RoleInfo {
+                        owner: Pallet::<T, I>::role_account(*id),
+                        metadata: Default::default(),
+                        destroying: false,
+                    },
+                );
+            }
+            if let Some(max) = ids.last() {
+                NextRoleId::<T, I>::mutate(|next| *next = (*next).max(max.saturating_add(1)));
+            }
+            log::info!(target: LOG_TARGET, "MigrateToV3 registered {} roles", count);
+            T::DbWeight::get().reads_writes(reads.saturating_add(1), count.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((Self::ids().0.len() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("failed to decode pre-upgrade state"))?;
+            ensure!(
+                RoleInfos::<T, I>::iter().count() as u32 == count,
+                "roles not registered"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// All migrations of this pallet in order
+pub type Migrations<T, I = ()> = (
+    v1::MigrateToV1<T, I>,
+    v2::MigrateToV2<T, I>,
+    v3::MigrateToV3<T, I>,
+);
@@ -0,0 +1,81 @@
+//! Call-RBAC origins for gating calls of other pallets on role membership.
+use super::*;
+use frame_support::traits::{EnsureOrigin, EnsureOriginWithArg};
+use frame_system::RawOrigin;
+use sp_std::marker::PhantomData;
+
+/// `Get<Role>` for `Role::Admin`
+pub struct AdminRole;
+impl Get<Role> for AdminRole {
+    fn get() -> Role {
+        Role::Admin
+    }
+}
+
+/// `Get<Role>` for `Role::Executer`
+pub struct ExecuterRole;
+impl Get<Role> for ExecuterRole {
+    fn get() -> Role {
+        Role::Executer
+    }
+}
+
+/// Ensure the origin is signed by an account with role `R` for id `Id`.
+/// Succeeds with the signer.
+pub struct EnsureRole<T, Id, R, I = ()>(PhantomData<(T, Id, R, I)>);
+
+/// Ensure the origin is signed by an Admin for id `Id`
+pub type EnsureRoleAdmin<T, Id, I = ()> = EnsureRole<T, Id, AdminRole, I>;
+
+/// Ensure the origin is signed by an Executer for id `Id`
+pub type EnsureRoleExecuter<T, Id, I = ()> = EnsureRole<T, Id, ExecuterRole, I>;
+
+impl<O, T, Id, R, I> EnsureOrigin<O> for EnsureRole<T, Id, R, I>
+where
+    O: Into<Result<RawOrigin<T::AccountId>, O>> + From<RawOrigin<T::AccountId>>,
+    T: Config<I>,
+    I: 'static,
+    Id: Get<RoleId>,
+    R: Get<Role>,
+{
+    type Success = T::AccountId;
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        EnsureRoleWithArg::<T, R, I>::try_origin(o, &Id::get())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        EnsureRoleWithArg::<T, R, I>::try_successful_origin(&Id::get())
+    }
+}
+
+/// Ensure the origin is signed by an account with role `R` for the input id.
+/// Succeeds with the signer.
+pub struct EnsureRoleWithArg<T, R, I = ()>(PhantomData<(T, R, I)>);
+
+impl<O, T, R, I> EnsureOriginWithArg<O, RoleId> for EnsureRoleWithArg<T, R, I>
+where
+    O: Into<Result<RawOrigin<T::AccountId>, O>> + From<RawOrigin<T::AccountId>>,
+    T: Config<I>,
+    I: 'static,
+    R: Get<Role>,
+{
+    type Success = T::AccountId;
+
+    fn try_origin(o: O, id: &RoleId) -> Result<Self::Success, O> {
+        o.into().and_then(|o| match o {
+            RawOrigin::Signed(who) if Pallet::<T, I>::role(*id, &who) == Some(R::get()) => Ok(who),
+            r => Err(O::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(id: &RoleId) -> Result<O, ()> {
+        let who: T::AccountId = frame_benchmarking::account("role", 0, 0);
+        if Pallet::<T, I>::role(*id, &who) != Some(R::get()) {
+            Pallet::<T, I>::do_grant(*id, &who, R::get(), None).map_err(|_| ())?;
+        }
+        Ok(O::from(RawOrigin::Signed(who)))
+    }
+}
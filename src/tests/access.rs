@@ -310,9 +310,9 @@ fn grant_access_updates_storage() {
             2,
             Role::Admin
         ));
-        assert_eq!(Roles::<Test>::get(0, 1).unwrap(), Role::Executer);
+        assert_eq!(CallRBAC::role(0, &1).unwrap(), Role::Executer);
         assert!(Roles::<Test>::get(1, 1).is_none());
-        assert_eq!(Roles::<Test>::get(0, 2).unwrap(), Role::Admin);
+        assert_eq!(CallRBAC::role(0, &2).unwrap(), Role::Admin);
         // executor has call permissions
         assert!(Permissions::<Test>::get(1, 0).is_some());
         // executor only has call permissions for `id` 0
@@ -344,3 +344,37 @@ fn revoke_access_updates_storage() {
         assert!(Permissions::<Test>::get(1, 0).is_none());
     });
 }
+
+#[test]
+fn cannot_grant_executer_access_for_more_than_max_roles() {
+    new_test_ext().execute_with(|| {
+        for id in 0..3 {
+            assert_ok!(CallRBAC::grant_access(
+                RawOrigin::Root.into(),
+                id,
+                1,
+                Role::Executer
+            ));
+        }
+        assert_eq!(PermissionCount::<Test>::get(1), 3);
+        assert_noop!(
+            CallRBAC::grant_access(RawOrigin::Root.into(), 3, 1, Role::Executer),
+            Error::<Test>::TooManyRoles
+        );
+        // admin roles are not scanned by execute_call
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            3,
+            1,
+            Role::Admin
+        ));
+        assert_ok!(CallRBAC::revoke_access(RawOrigin::Root.into(), 0, 1));
+        assert_eq!(PermissionCount::<Test>::get(1), 2);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            4,
+            1,
+            Role::Executer
+        ));
+    });
+}
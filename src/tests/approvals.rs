@@ -0,0 +1,218 @@
+//! Call-RBAC proposal and approval unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, bounded_vec, weights::Weight};
+use frame_system::RawOrigin;
+
+/// Id 0 permits transfer of 3 from 1 to 2 with approval, 1 is Admin, 2 and 3 are Executers
+fn setup() -> CallHashOf<Test> {
+    setup_executer(3);
+    let hash = call_hash(call_transfer(2, 3));
+    assert_ok!(CallRBAC::set_approval_required(
+        RawOrigin::Root.into(),
+        0,
+        hash,
+        true
+    ));
+    for (who, role) in [(1, Role::Admin), (3, Role::Executer)] {
+        assert_ok!(CallRBAC::grant_access(RawOrigin::Root.into(), 0, who, role));
+    }
+    hash
+}
+
+#[test]
+fn call_requiring_approval_cannot_be_executed_directly() {
+    new_test_ext().execute_with(|| {
+        setup();
+        assert_noop!(
+            CallRBAC::execute_call_with_role(
+                RawOrigin::Signed(2).into(),
+                0,
+                Box::new(call_transfer(2, 3))
+            ),
+            Error::<Test>::CallRequiresApproval
+        );
+        assert_noop!(
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 3))),
+            Error::<Test>::CallNotPermitted
+        );
+        assert!(CallRBAC::can_execute(&2, &call_transfer(2, 3)).is_none());
+    });
+}
+
+#[test]
+fn id_requiring_approval_is_skipped_for_execute_call() {
+    new_test_ext().execute_with(|| {
+        setup();
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            1,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 3),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            1,
+            2,
+            Role::Executer
+        ));
+        assert_eq!(
+            CallRBAC::can_execute(&2, &call_transfer(2, 3)),
+            Some((1, RawOrigin::Signed(1).into()))
+        );
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(2, 3))
+        ));
+        assert_eq!(Balances::free_balance(&2), 13);
+    });
+}
+
+#[test]
+fn proposal_dispatches_after_threshold_approvals() {
+    new_test_ext().execute_with(|| {
+        let hash = setup();
+        assert_noop!(
+            CallRBAC::propose_call(RawOrigin::Signed(1).into(), 0, hash),
+            Error::<Test>::CallNotPermitted
+        );
+        assert_ok!(CallRBAC::propose_call(RawOrigin::Signed(2).into(), 0, hash));
+        System::assert_last_event(Event::<Test>::CallProposed(0, hash, 2).into());
+        assert_noop!(
+            CallRBAC::propose_call(RawOrigin::Signed(3).into(), 0, hash),
+            Error::<Test>::ProposalExists
+        );
+        assert_noop!(
+            CallRBAC::approve_call(RawOrigin::Signed(2).into(), 0, hash, Weight::MAX),
+            Error::<Test>::ProposerCannotApprove
+        );
+        assert_ok!(CallRBAC::approve_call(
+            RawOrigin::Signed(1).into(),
+            0,
+            hash,
+            Weight::MAX
+        ));
+        assert_noop!(
+            CallRBAC::approve_call(RawOrigin::Signed(1).into(), 0, hash, Weight::MAX),
+            Error::<Test>::AlreadyApproved
+        );
+        assert_eq!(Balances::free_balance(&2), 10);
+        assert_noop!(
+            CallRBAC::approve_call(RawOrigin::Signed(3).into(), 0, hash, Weight::zero()),
+            Error::<Test>::MaxWeightTooLow
+        );
+        assert_ok!(CallRBAC::approve_call(
+            RawOrigin::Signed(3).into(),
+            0,
+            hash,
+            Weight::MAX
+        ));
+        System::assert_last_event(Event::<Test>::ProposalExecuted(0, hash, Ok(())).into());
+        assert_eq!(Balances::free_balance(&1), 7);
+        assert_eq!(Balances::free_balance(&2), 13);
+        assert!(Proposals::<Test>::get(0, hash).is_none());
+        assert_eq!(Approvals::<Test>::iter_prefix((0, hash)).count(), 0);
+    });
+}
+
+#[test]
+fn proposal_expires_and_can_be_cancelled() {
+    new_test_ext().execute_with(|| {
+        let hash = setup();
+        assert_ok!(CallRBAC::propose_call(RawOrigin::Signed(2).into(), 0, hash));
+        assert_noop!(
+            CallRBAC::cancel_proposal(RawOrigin::Signed(3).into(), 0, hash),
+            Error::<Test>::CallerNotAdmin
+        );
+        System::set_block_number(11);
+        assert_noop!(
+            CallRBAC::approve_call(RawOrigin::Signed(1).into(), 0, hash, Weight::MAX),
+            Error::<Test>::ProposalExpired
+        );
+        // anyone may clean up an expired proposal
+        assert_ok!(CallRBAC::cancel_proposal(
+            RawOrigin::Signed(3).into(),
+            0,
+            hash
+        ));
+        System::assert_last_event(Event::<Test>::ProposalCancelled(0, hash).into());
+        assert_ok!(CallRBAC::propose_call(RawOrigin::Signed(2).into(), 0, hash));
+        assert_ok!(CallRBAC::cancel_proposal(
+            RawOrigin::Signed(1).into(),
+            0,
+            hash
+        ));
+        assert!(Proposals::<Test>::get(0, hash).is_none());
+    });
+}
+
+#[test]
+fn expired_proposal_is_replaced() {
+    new_test_ext().execute_with(|| {
+        let hash = setup();
+        assert_ok!(CallRBAC::propose_call(RawOrigin::Signed(2).into(), 0, hash));
+        assert_ok!(CallRBAC::approve_call(
+            RawOrigin::Signed(1).into(),
+            0,
+            hash,
+            Weight::MAX
+        ));
+        System::set_block_number(11);
+        assert_ok!(CallRBAC::propose_call(RawOrigin::Signed(3).into(), 0, hash));
+        System::assert_last_event(Event::<Test>::CallProposed(0, hash, 3).into());
+        assert_eq!(
+            Proposals::<Test>::get(0, hash),
+            Some(Proposal {
+                proposer: 3,
+                expiry: 21,
+                approvals: 0,
+            })
+        );
+        assert_eq!(Approvals::<Test>::iter_prefix((0, hash)).count(), 0);
+    });
+}
+
+#[test]
+fn approved_dispatch_counts_against_quotas() {
+    new_test_ext().execute_with(|| {
+        let hash = setup();
+        let quota = Quota {
+            per_period: None,
+            total: Some(1),
+        };
+        for target in [QuotaTarget::Executer(2), QuotaTarget::Call(hash)] {
+            assert_ok!(CallRBAC::set_quota(
+                RawOrigin::Root.into(),
+                0,
+                target,
+                Some(quota)
+            ));
+        }
+        assert_ok!(CallRBAC::propose_call(RawOrigin::Signed(2).into(), 0, hash));
+        for approver in [1, 3] {
+            assert_ok!(CallRBAC::approve_call(
+                RawOrigin::Signed(approver).into(),
+                0,
+                hash,
+                Weight::MAX
+            ));
+        }
+        System::assert_last_event(Event::<Test>::ProposalExecuted(0, hash, Ok(())).into());
+        assert_eq!(Usages::<Test>::get(0, QuotaTarget::Executer(2)).total, 1);
+        assert_eq!(Usages::<Test>::get(0, QuotaTarget::Call(hash)).total, 1);
+
+        assert_ok!(CallRBAC::propose_call(RawOrigin::Signed(2).into(), 0, hash));
+        assert_ok!(CallRBAC::approve_call(
+            RawOrigin::Signed(1).into(),
+            0,
+            hash,
+            Weight::MAX
+        ));
+        assert_noop!(
+            CallRBAC::approve_call(RawOrigin::Signed(3).into(), 0, hash, Weight::MAX),
+            Error::<Test>::QuotaExceeded
+        );
+        assert_eq!(Balances::free_balance(&2), 13);
+    });
+}
@@ -0,0 +1,83 @@
+//! Call-RBAC audit log unit tests.
+use super::*;
+use frame_support::{assert_ok, bounded_vec};
+use frame_system::RawOrigin;
+
+fn entry(actor: Option<u64>, block: u64, action: AuditActionOf<Test>) -> AuditEntryOf<Test> {
+    AuditEntry {
+        actor,
+        block,
+        action,
+    }
+}
+
+#[test]
+fn audit_log_records_privileged_actions() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 1),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        System::set_block_number(2);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Signed(1).into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(2, 1))
+        ));
+        assert_eq!(
+            CallRBAC::audit_log(0),
+            vec![
+                entry(None, 1, AuditAction::CallsChanged),
+                entry(None, 1, AuditAction::Granted(1, Role::Admin)),
+                entry(Some(1), 2, AuditAction::Granted(2, Role::Executer)),
+                entry(
+                    Some(2),
+                    2,
+                    AuditAction::Executed(call_hash(call_transfer(2, 1)))
+                ),
+            ]
+        );
+        assert!(CallRBAC::audit_log(1).is_empty());
+    });
+}
+
+#[test]
+fn audit_log_prunes_oldest_entries() {
+    new_test_ext().execute_with(|| {
+        for who in 1..4 {
+            assert_ok!(CallRBAC::grant_access(
+                RawOrigin::Root.into(),
+                0,
+                who,
+                Role::Executer
+            ));
+        }
+        for who in 1..3 {
+            assert_ok!(CallRBAC::revoke_access(RawOrigin::Root.into(), 0, who));
+        }
+        assert_eq!(
+            CallRBAC::audit_log(0),
+            vec![
+                entry(None, 1, AuditAction::Granted(2, Role::Executer)),
+                entry(None, 1, AuditAction::Granted(3, Role::Executer)),
+                entry(None, 1, AuditAction::Revoked(1, Role::Executer)),
+                entry(None, 1, AuditAction::Revoked(2, Role::Executer)),
+            ]
+        );
+    });
+}
@@ -1,14 +1,22 @@
 //! Call-RBAC set_calls and execute_call unit tests.
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, bounded_vec, dispatch::GetDispatchInfo};
 use frame_system::RawOrigin;
 
 #[test]
 fn set_calls_emits_event() {
     new_test_ext().execute_with(|| {
-        assert_ok!(CallRBAC::set_calls(RawOrigin::Root.into(), 0, vec![]));
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![]
+        ));
         System::assert_last_event(Event::<Test>::CallsUpdated(0).into());
-        assert_ok!(CallRBAC::set_calls(RawOrigin::Root.into(), 1, vec![]));
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            1,
+            bounded_vec![]
+        ));
         System::assert_last_event(Event::<Test>::CallsUpdated(1).into());
     });
 }
@@ -19,13 +27,13 @@ fn set_call_only_for_super_user_origin() {
         assert_ok!(CallRBAC::set_calls(
             RawOrigin::Root.into(),
             0,
-            vec![CallOrigin {
+            bounded_vec![CallOrigin {
                 call: call_transfer(2, 3),
                 origin: RawOrigin::Signed(1).into(),
             }]
         ));
         assert_noop!(
-            CallRBAC::set_calls(RawOrigin::Signed(1).into(), 0, vec![]),
+            CallRBAC::set_calls(RawOrigin::Signed(1).into(), 0, bounded_vec![]),
             frame_support::error::BadOrigin
         );
     });
@@ -37,17 +45,55 @@ fn set_calls_updates_storage() {
         assert_ok!(CallRBAC::set_calls(
             RawOrigin::Root.into(),
             0,
-            vec![CallOrigin {
+            bounded_vec![CallOrigin {
                 call: call_transfer(2, 3),
                 origin: RawOrigin::Signed(1).into(),
             }]
         ));
         assert_eq!(
-            CallOrigins::<Test>::get(0, call_transfer(2, 3)),
+            CallOrigins::<Test>::get(0, call_hash(call_transfer(2, 3))),
             Some(RawOrigin::Signed(1).into())
         );
-        assert_ok!(CallRBAC::set_calls(RawOrigin::Root.into(), 0, vec![]));
-        assert_eq!(CallOrigins::<Test>::get(0, call_transfer(2, 3)), None);
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![]
+        ));
+        assert_eq!(
+            CallOrigins::<Test>::get(0, call_hash(call_transfer(2, 3))),
+            None
+        );
+    });
+}
+
+#[test]
+fn set_calls_stores_call_once_per_hash() {
+    new_test_ext().execute_with(|| {
+        let hash = call_hash(call_transfer(2, 3));
+        for id in 0..2 {
+            assert_ok!(CallRBAC::set_calls(
+                RawOrigin::Root.into(),
+                id,
+                bounded_vec![CallOrigin {
+                    call: call_transfer(2, 3),
+                    origin: RawOrigin::Signed(1).into(),
+                }]
+            ));
+        }
+        assert_eq!(Calls::<Test>::get(hash).unwrap().1, 2);
+        assert_eq!(CallRBAC::stored_call(hash), Some(call_transfer(2, 3)));
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![]
+        ));
+        assert_eq!(Calls::<Test>::get(hash).unwrap().1, 1);
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            1,
+            bounded_vec![]
+        ));
+        assert!(Calls::<Test>::get(hash).is_none());
     });
 }
 
@@ -57,7 +103,7 @@ fn execute_call_works_for_executor_not_admin() {
         assert_ok!(CallRBAC::set_calls(
             RawOrigin::Root.into(),
             0,
-            vec![CallOrigin {
+            bounded_vec![CallOrigin {
                 call: call_transfer(2, 3),
                 origin: RawOrigin::Signed(1).into(),
             }]
@@ -88,3 +134,298 @@ fn execute_call_works_for_executor_not_admin() {
         assert_eq!(Balances::free_balance(&2), 13);
     });
 }
+
+#[test]
+fn set_calls_fails_if_call_too_long() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::set_calls(
+                RawOrigin::Root.into(),
+                0,
+                bounded_vec![CallOrigin {
+                    call: frame_system::Call::remark {
+                        remark: vec![0; 64]
+                    }
+                    .into(),
+                    origin: RawOrigin::Signed(1).into(),
+                }]
+            ),
+            Error::<Test>::CallTooLong
+        );
+    });
+}
+
+fn transfer_and_origin(dest: u64, value: u64) -> CallAndOrigin<Test> {
+    CallOrigin {
+        call: call_transfer(dest, value),
+        origin: RawOrigin::Signed(1).into(),
+    }
+}
+
+#[test]
+fn add_calls_updates_storage_and_emits_event() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::add_calls(RawOrigin::Signed(1).into(), 0, bounded_vec![]),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![transfer_and_origin(2, 3)]
+        ));
+        assert_ok!(CallRBAC::add_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![transfer_and_origin(2, 3), transfer_and_origin(2, 4)]
+        ));
+        System::assert_last_event(
+            Event::<Test>::CallsAdded(
+                0,
+                vec![
+                    call_hash(call_transfer(2, 3)),
+                    call_hash(call_transfer(2, 4)),
+                ],
+            )
+            .into(),
+        );
+        assert_eq!(CallCount::<Test>::get(0), 2);
+        assert!(CallOrigins::<Test>::contains_key(
+            0,
+            call_hash(call_transfer(2, 3))
+        ));
+        assert!(CallOrigins::<Test>::contains_key(
+            0,
+            call_hash(call_transfer(2, 4))
+        ));
+    });
+}
+
+#[test]
+fn add_calls_enforces_max_calls_for_id() {
+    new_test_ext().execute_with(|| {
+        let calls: Vec<_> = (0..10).map(|value| transfer_and_origin(2, value)).collect();
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            calls.try_into().unwrap()
+        ));
+        assert_noop!(
+            CallRBAC::add_calls(
+                RawOrigin::Root.into(),
+                0,
+                bounded_vec![transfer_and_origin(2, 10)]
+            ),
+            Error::<Test>::TooManyCalls
+        );
+        // updating the origin of an accessible call does not count
+        assert_ok!(CallRBAC::add_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![transfer_and_origin(2, 9)]
+        ));
+        assert_eq!(CallCount::<Test>::get(0), 10);
+    });
+}
+
+#[test]
+fn remove_calls_updates_storage_and_emits_event() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![transfer_and_origin(2, 3), transfer_and_origin(2, 4)]
+        ));
+        assert_noop!(
+            CallRBAC::remove_calls(RawOrigin::Root.into(), 0, bounded_vec![call_transfer(2, 5)]),
+            Error::<Test>::CallOriginNotSet
+        );
+        assert_ok!(CallRBAC::remove_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![call_transfer(2, 3)]
+        ));
+        System::assert_last_event(
+            Event::<Test>::CallsRemoved(0, vec![call_hash(call_transfer(2, 3))]).into(),
+        );
+        assert_eq!(CallCount::<Test>::get(0), 1);
+        assert!(CallOrigins::<Test>::get(0, call_hash(call_transfer(2, 3))).is_none());
+        assert!(Calls::<Test>::get(call_hash(call_transfer(2, 3))).is_none());
+        assert!(CallOrigins::<Test>::contains_key(
+            0,
+            call_hash(call_transfer(2, 4))
+        ));
+    });
+}
+
+#[test]
+fn execute_call_with_role_uses_origin_of_input_id() {
+    new_test_ext().execute_with(|| {
+        for (id, from) in [(0, 1), (1, 2)] {
+            assert_ok!(CallRBAC::set_calls(
+                RawOrigin::Root.into(),
+                id,
+                bounded_vec![CallOrigin {
+                    call: call_transfer(3, 3),
+                    origin: RawOrigin::Signed(from).into(),
+                }]
+            ));
+            assert_ok!(CallRBAC::grant_access(
+                RawOrigin::Root.into(),
+                id,
+                3,
+                Role::Executer
+            ));
+        }
+        assert_ok!(CallRBAC::execute_call_with_role(
+            RawOrigin::Signed(3).into(),
+            1,
+            Box::new(call_transfer(3, 3))
+        ));
+        assert_eq!(Balances::free_balance(&1), 10);
+        assert_eq!(Balances::free_balance(&2), 7);
+        assert_ok!(CallRBAC::execute_call_with_role(
+            RawOrigin::Signed(3).into(),
+            0,
+            Box::new(call_transfer(3, 3))
+        ));
+        assert_eq!(Balances::free_balance(&1), 7);
+        assert_eq!(Balances::free_balance(&3), 6);
+    });
+}
+
+#[test]
+fn execute_call_with_role_only_for_executer_of_input_id() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![transfer_and_origin(2, 3)]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Admin
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            1,
+            2,
+            Role::Executer
+        ));
+        assert_noop!(
+            CallRBAC::execute_call_with_role(
+                RawOrigin::Signed(2).into(),
+                0,
+                Box::new(call_transfer(2, 3))
+            ),
+            Error::<Test>::CallNotPermitted
+        );
+        assert_noop!(
+            CallRBAC::execute_call_with_role(
+                RawOrigin::Signed(2).into(),
+                1,
+                Box::new(call_transfer(2, 3))
+            ),
+            Error::<Test>::CallNotPermitted
+        );
+    });
+}
+
+#[test]
+fn runtime_api_queries_roles_and_calls() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![transfer_and_origin(2, 3)]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            1,
+            2,
+            Role::Admin
+        ));
+        assert_eq!(
+            CallRBAC::allowed_calls(&2),
+            vec![(0, call_transfer(2, 3), RawOrigin::Signed(1).into())]
+        );
+        assert!(CallRBAC::allowed_calls(&1).is_empty());
+        let mut members = CallRBAC::role_members(0);
+        members.sort_by_key(|(who, _)| *who);
+        assert_eq!(members, vec![(1, Role::Admin), (2, Role::Executer)]);
+        let mut roles = CallRBAC::account_roles(&2);
+        roles.sort_by_key(|(id, _)| *id);
+        assert_eq!(roles, vec![(0, Role::Executer), (1, Role::Admin)]);
+        assert_eq!(
+            CallRBAC::can_execute(&2, &call_transfer(2, 3)),
+            Some((0, RawOrigin::Signed(1).into()))
+        );
+        assert_eq!(CallRBAC::can_execute(&2, &call_transfer(2, 4)), None);
+        assert_eq!(CallRBAC::can_execute(&1, &call_transfer(2, 3)), None);
+    });
+}
+
+#[test]
+fn execute_call_emits_result_and_actual_weight() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![transfer_and_origin(2, 3), transfer_and_origin(2, 30)]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        let info =
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 3)))
+                .unwrap();
+        System::assert_last_event(
+            Event::<Test>::CallExecuted {
+                who: 2,
+                role_id: 0,
+                call_hash: call_hash(call_transfer(2, 3)),
+                result: Ok(()),
+            }
+            .into(),
+        );
+        assert_eq!(
+            info.actual_weight,
+            Some(
+                <Test as Config>::WeightInfo::execute_call(1)
+                    .saturating_add(call_transfer(2, 3).get_dispatch_info().weight)
+            )
+        );
+        // failed calls are reported in the event, not as an error
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(2, 30))
+        ));
+        assert!(matches!(
+            System::events().last().map(|record| &record.event),
+            Some(RuntimeEvent::CallRBAC(Event::CallExecuted {
+                who: 2,
+                role_id: 0,
+                result: Err(_),
+                ..
+            }))
+        ));
+        assert_eq!(Balances::free_balance(&1), 7);
+    });
+}
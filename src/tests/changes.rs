@@ -0,0 +1,164 @@
+//! Call-RBAC delayed permission change unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, bounded_vec};
+use frame_system::RawOrigin;
+
+#[test]
+fn changes_apply_after_delay() {
+    new_test_ext().execute_with(|| {
+        ChangeDelay::set(5);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        System::assert_last_event(
+            Event::<Test>::ChangeAnnounced(0, Change::Grant(0, 1, Role::Admin, None), 6).into(),
+        );
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 3),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert!(CallRBAC::role(0, &1).is_none());
+        run_idle(5);
+        assert!(CallRBAC::role(0, &1).is_none());
+        assert_eq!(ChangeCursor::<Test>::get(), 0);
+        run_idle(6);
+        System::assert_has_event(Event::<Test>::AccessGranted(0, 1, Role::Admin).into());
+        System::assert_last_event(Event::<Test>::ChangeApplied(1, Ok(())).into());
+        assert_eq!(CallRBAC::role(0, &1), Some(Role::Admin));
+        assert_eq!(CallCount::<Test>::get(0), 1);
+        assert_eq!(ChangeCursor::<Test>::get(), 2);
+        assert_eq!(PendingChanges::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn failed_change_is_reported_and_skipped() {
+    new_test_ext().execute_with(|| {
+        ChangeDelay::set(1);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Admin
+        ));
+        run_idle(2);
+        System::assert_last_event(
+            Event::<Test>::ChangeApplied(1, Err(Error::<Test>::AlreadyGrantedAccess.into())).into(),
+        );
+        assert_eq!(CallRBAC::role(0, &2), Some(Role::Executer));
+        assert_eq!(ChangeCursor::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn cancel_pending_change_only_for_super_user() {
+    new_test_ext().execute_with(|| {
+        ChangeDelay::set(1);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_noop!(
+            CallRBAC::cancel_pending_change(RawOrigin::Signed(1).into(), 0),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(CallRBAC::cancel_pending_change(RawOrigin::Root.into(), 0));
+        System::assert_last_event(Event::<Test>::ChangeCancelled(0).into());
+        assert_noop!(
+            CallRBAC::cancel_pending_change(RawOrigin::Root.into(), 0),
+            Error::<Test>::ChangeDNE
+        );
+        run_idle(2);
+        assert!(CallRBAC::role(0, &2).is_none());
+        assert_eq!(ChangeCursor::<Test>::get(), 1);
+    });
+}
+
+#[test]
+fn settings_changes_are_delayed_until_applied() {
+    new_test_ext().execute_with(|| {
+        let hash = call_hash(call_transfer(2, 3));
+        assert_ok!(CallRBAC::set_approval_required(
+            RawOrigin::Root.into(),
+            0,
+            hash,
+            true
+        ));
+        ChangeDelay::set(1);
+        assert_ok!(CallRBAC::set_approval_required(
+            RawOrigin::Root.into(),
+            0,
+            hash,
+            false
+        ));
+        assert_ok!(CallRBAC::set_fee_policy(
+            RawOrigin::Root.into(),
+            0,
+            FeePolicy::Refund
+        ));
+        assert_ok!(CallRBAC::reset_usage(
+            RawOrigin::Root.into(),
+            0,
+            QuotaTarget::Call(hash)
+        ));
+        assert!(ApprovalRequired::<Test>::contains_key(0, hash));
+        assert_eq!(FeePolicies::<Test>::get(0), FeePolicy::default());
+        assert_eq!(PendingChanges::<Test>::iter().count(), 3);
+        run_idle(2);
+        System::assert_has_event(Event::<Test>::ApprovalRequirementUpdated(0, hash, false).into());
+        assert!(!ApprovalRequired::<Test>::contains_key(0, hash));
+        assert_eq!(FeePolicies::<Test>::get(0), FeePolicy::Refund);
+        assert_eq!(
+            CallRBAC::audit_log(0)
+                .last()
+                .map(|entry| entry.action.clone()),
+            Some(AuditAction::SettingsChanged)
+        );
+        assert_eq!(PendingChanges::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn grant_is_checked_when_announced() {
+    new_test_ext().execute_with(|| {
+        for id in 0..3 {
+            assert_ok!(CallRBAC::grant_access(
+                RawOrigin::Root.into(),
+                id,
+                2,
+                Role::Executer
+            ));
+        }
+        ChangeDelay::set(1);
+        assert_noop!(
+            CallRBAC::grant_access(RawOrigin::Root.into(), 0, 2, Role::Admin),
+            Error::<Test>::AlreadyGrantedAccess
+        );
+        assert_noop!(
+            CallRBAC::grant_access(RawOrigin::Root.into(), 3, 2, Role::Executer),
+            Error::<Test>::TooManyRoles
+        );
+        assert_noop!(
+            CallRBAC::grant_access_until(RawOrigin::Root.into(), 3, 4, Role::Executer, 2),
+            Error::<Test>::ExpiryInPast
+        );
+        GrantDeposit::set(5);
+        assert!(CallRBAC::grant_access(RawOrigin::Root.into(), 3, 4, Role::Admin).is_err());
+        assert_eq!(PendingChanges::<Test>::iter().count(), 0);
+    });
+}
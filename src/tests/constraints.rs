@@ -0,0 +1,150 @@
+//! Call-RBAC set_call_constraints unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, bounded_vec};
+use frame_system::RawOrigin;
+
+#[test]
+fn set_call_constraints_only_for_super_user_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::set_call_constraints(RawOrigin::Signed(1).into(), 0, bounded_vec![]),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(CallRBAC::set_call_constraints(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![]
+        ));
+        System::assert_last_event(Event::<Test>::CallConstraintsUpdated(0).into());
+    });
+}
+
+#[test]
+fn set_call_constraints_updates_storage() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_call_constraints(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: TransferConstraint::Any,
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_eq!(
+            CallConstraints::<Test>::get(0, TransferConstraint::Any),
+            Some(RawOrigin::Signed(1).into())
+        );
+        assert_ok!(CallRBAC::set_call_constraints(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![]
+        ));
+        assert_eq!(
+            CallConstraints::<Test>::get(0, TransferConstraint::Any),
+            None
+        );
+    });
+}
+
+#[test]
+fn value_constraint_permits_calls_up_to_value() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_call_constraints(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: TransferConstraint::ValueAtMost(5),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(2, 4))
+        ));
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(2, 5))
+        ));
+        assert_noop!(
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 6))),
+            Error::<Test>::CallNotPermitted
+        );
+        assert_eq!(Balances::free_balance(&1), 1);
+        assert_eq!(Balances::free_balance(&2), 19);
+    });
+}
+
+#[test]
+fn dest_constraint_permits_calls_to_dest_set() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_call_constraints(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: TransferConstraint::DestIn(bounded_vec![2, 3]),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(3, 2))
+        ));
+        assert_noop!(
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(4, 2))),
+            Error::<Test>::CallNotPermitted
+        );
+        assert_eq!(Balances::free_balance(&3), 2);
+    });
+}
+
+#[test]
+fn exact_call_takes_precedence_over_constraint() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_call_constraints(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: TransferConstraint::Any,
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(1, 3),
+                origin: RawOrigin::Signed(2).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(1, 3))
+        ));
+        assert_eq!(Balances::free_balance(&1), 13);
+        assert_eq!(Balances::free_balance(&2), 7);
+    });
+}
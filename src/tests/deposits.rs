@@ -0,0 +1,173 @@
+//! Call-RBAC storage deposit unit tests.
+use super::*;
+use frame_support::{assert_ok, bounded_vec, traits::Hooks, weights::Weight};
+use frame_system::RawOrigin;
+
+/// Transfer amount from who to the `DepositPayer`
+fn fund_deposit_account(who: u64, amount: u64) {
+    assert_ok!(Balances::transfer_allow_death(
+        RawOrigin::Signed(who).into(),
+        DepositAccount::get(),
+        amount
+    ));
+}
+
+#[test]
+fn grant_deposit_held_from_admin_and_released_on_revoke() {
+    new_test_ext().execute_with(|| {
+        GrantDeposit::set(2);
+        let account = DepositAccount::get();
+        // the DepositPayer pays for grants by unsigned origins
+        assert!(CallRBAC::grant_access(RawOrigin::Root.into(), 0, 1, Role::Admin).is_err());
+        assert_ok!(CallRBAC::fund_role(RawOrigin::Signed(2).into(), 0, 5));
+        assert!(CallRBAC::grant_access(RawOrigin::Root.into(), 0, 1, Role::Admin).is_err());
+        fund_deposit_account(2, 5);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        assert_eq!(Balances::reserved_balance(&account), 2);
+        assert_eq!(Balances::reserved_balance(&CallRBAC::role_account(0)), 0);
+
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Signed(1).into(),
+            0,
+            3,
+            Role::Executer
+        ));
+        assert_eq!(Balances::reserved_balance(&1), 2);
+        assert_eq!(GrantDeposits::<Test>::get(0, 3), Some((1, 2)));
+        assert_ok!(CallRBAC::do_try_state());
+
+        assert_ok!(CallRBAC::revoke_access(RawOrigin::Signed(1).into(), 0, 3));
+        assert_eq!(Balances::reserved_balance(&1), 0);
+        assert!(GrantDeposits::<Test>::get(0, 3).is_none());
+    });
+}
+
+#[test]
+fn grant_fails_if_admin_cannot_pay_deposit() {
+    new_test_ext().execute_with(|| {
+        GrantDeposit::set(2);
+        fund_deposit_account(2, 5);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            3,
+            Role::Admin
+        ));
+        assert!(CallRBAC::grant_access(RawOrigin::Signed(3).into(), 0, 4, Role::Executer).is_err());
+        assert!(CallRBAC::role(0, &4).is_none());
+    });
+}
+
+#[test]
+fn grant_deposit_released_on_expiry() {
+    new_test_ext().execute_with(|| {
+        GrantDeposit::set(2);
+        fund_deposit_account(2, 5);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        assert_ok!(CallRBAC::grant_access_until(
+            RawOrigin::Signed(1).into(),
+            0,
+            3,
+            Role::Executer,
+            3
+        ));
+        assert_eq!(Balances::reserved_balance(&1), 2);
+        System::set_block_number(3);
+        CallRBAC::on_idle(3, Weight::MAX);
+        assert_eq!(Balances::reserved_balance(&1), 0);
+        assert!(GrantDeposits::<Test>::get(0, 3).is_none());
+    });
+}
+
+#[test]
+fn call_deposit_held_per_byte_and_released_on_replacement() {
+    new_test_ext().execute_with(|| {
+        CallDepositPerByte::set(1);
+        let account = DepositAccount::get();
+        let len = call_transfer(2, 3).encode().len() as u64;
+        fund_deposit_account(1, 9);
+        fund_deposit_account(2, 9);
+
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 3),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_eq!(Balances::reserved_balance(&account), len);
+        assert_eq!(
+            CallDeposits::<Test>::get(0, call_hash(call_transfer(2, 3))),
+            Some((account, len))
+        );
+
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 4),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_eq!(Balances::reserved_balance(&account), len);
+        assert!(CallDeposits::<Test>::get(0, call_hash(call_transfer(2, 3))).is_none());
+        assert_ok!(CallRBAC::do_try_state());
+
+        assert_ok!(CallRBAC::remove_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![call_transfer(2, 4)]
+        ));
+        assert_eq!(Balances::reserved_balance(&account), 0);
+    });
+}
+
+#[test]
+fn destroy_role_releases_deposits() {
+    new_test_ext().execute_with(|| {
+        GrantDeposit::set(2);
+        CallDepositPerByte::set(1);
+        fund_deposit_account(1, 5);
+        fund_deposit_account(2, 9);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Signed(1).into(),
+            0,
+            3,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::add_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 3),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_eq!(Balances::reserved_balance(&1), 2);
+
+        assert_ok!(CallRBAC::destroy_role(RawOrigin::Root.into(), 0, 10));
+        System::assert_last_event(Event::<Test>::RoleDestroyed(0).into());
+        assert_eq!(Balances::reserved_balance(&1), 0);
+        assert_eq!(Balances::reserved_balance(&DepositAccount::get()), 0);
+        assert_eq!(Balances::free_balance(&DepositAccount::get()), 14);
+        assert_eq!(GrantDeposits::<Test>::iter().count(), 0);
+        assert_eq!(CallDeposits::<Test>::iter().count(), 0);
+    });
+}
@@ -0,0 +1,132 @@
+//! Call-RBAC time-limited grant unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, bounded_vec};
+use frame_system::RawOrigin;
+
+#[test]
+fn grant_access_until_fails_if_expiry_not_after_now() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::grant_access_until(RawOrigin::Root.into(), 0, 2, Role::Executer, 1),
+            Error::<Test>::ExpiryInPast
+        );
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        assert_noop!(
+            CallRBAC::grant_access_until(RawOrigin::Signed(1).into(), 0, 2, Role::Admin, 5),
+            Error::<Test>::AdminOnlyGrantsExecuterAccess
+        );
+        assert_ok!(CallRBAC::grant_access_until(
+            RawOrigin::Signed(1).into(),
+            0,
+            2,
+            Role::Executer,
+            5
+        ));
+        System::assert_last_event(
+            Event::<Test>::AccessGrantedUntil(0, 2, Role::Executer, 5).into(),
+        );
+        assert!(ExpiryQueue::<Test>::contains_key(5, (0, 2)));
+        assert_eq!(ExpiryCursor::<Test>::get(), Some(5));
+    });
+}
+
+#[test]
+fn expired_grant_is_not_permitted_to_execute() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 3),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access_until(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer,
+            3
+        ));
+        System::set_block_number(2);
+        assert_eq!(CallRBAC::role(0, &2), Some(Role::Executer));
+        assert!(CallRBAC::can_execute(&2, &call_transfer(2, 3)).is_some());
+        System::set_block_number(3);
+        assert_eq!(CallRBAC::role(0, &2), None);
+        assert!(CallRBAC::can_execute(&2, &call_transfer(2, 3)).is_none());
+        assert!(CallRBAC::role_members(0).is_empty());
+        assert_noop!(
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 3))),
+            Error::<Test>::CallNotPermitted
+        );
+        // expired grant may be replaced before it is removed
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert!(!ExpiryQueue::<Test>::contains_key(3, (0, 2)));
+        assert_eq!(PermissionCount::<Test>::get(2), 1);
+    });
+}
+
+#[test]
+fn on_idle_removes_expired_grants() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::grant_access_until(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer,
+            3
+        ));
+        assert_ok!(CallRBAC::grant_access_until(
+            RawOrigin::Root.into(),
+            1,
+            2,
+            Role::Admin,
+            5
+        ));
+        run_idle(2);
+        assert!(Roles::<Test>::get(0, 2).is_some());
+        run_idle(4);
+        System::assert_last_event(Event::<Test>::AccessExpired(0, 2, Role::Executer).into());
+        assert!(Roles::<Test>::get(0, 2).is_none());
+        assert!(Permissions::<Test>::get(2, 0).is_none());
+        assert_eq!(PermissionCount::<Test>::get(2), 0);
+        assert!(Roles::<Test>::get(1, 2).is_some());
+        assert_eq!(ExpiryCursor::<Test>::get(), Some(5));
+        run_idle(5);
+        System::assert_last_event(Event::<Test>::AccessExpired(1, 2, Role::Admin).into());
+        assert!(Roles::<Test>::get(1, 2).is_none());
+        assert_eq!(ExpiryQueue::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn on_idle_does_not_expire_revoked_grants() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::grant_access_until(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer,
+            3
+        ));
+        assert_ok!(CallRBAC::revoke_access(RawOrigin::Root.into(), 0, 2));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        run_idle(3);
+        assert_eq!(CallRBAC::role(0, &2), Some(Role::Executer));
+    });
+}
@@ -0,0 +1,107 @@
+//! Call-RBAC fund_role, set_fee_policy and fee payment unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, dispatch::Pays};
+use frame_system::RawOrigin;
+
+#[test]
+fn fund_role_transfers_to_role_account() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::fund_role(RawOrigin::Signed(1).into(), 0, 5));
+        System::assert_last_event(Event::<Test>::RoleFunded(0, 1, 5).into());
+        assert_eq!(Balances::free_balance(&CallRBAC::role_account(0)), 5);
+        assert_eq!(Balances::free_balance(&CallRBAC::role_account(1)), 0);
+        assert_eq!(Balances::free_balance(&1), 5);
+    });
+}
+
+#[test]
+fn set_fee_policy_only_for_super_user_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::set_fee_policy(RawOrigin::Signed(1).into(), 0, FeePolicy::Free),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(CallRBAC::set_fee_policy(
+            RawOrigin::Root.into(),
+            0,
+            FeePolicy::Free
+        ));
+        System::assert_last_event(Event::<Test>::FeePolicyUpdated(0, FeePolicy::Free).into());
+        assert_eq!(FeePolicies::<Test>::get(0), FeePolicy::Free);
+        assert_eq!(FeePolicies::<Test>::get(1), FeePolicy::Executer);
+    });
+}
+
+#[test]
+fn executer_pays_by_default() {
+    new_test_ext().execute_with(|| {
+        setup_executer(3);
+        assert_ok!(CallRBAC::fund_role(RawOrigin::Signed(1).into(), 0, 5));
+        let info =
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 3)))
+                .unwrap();
+        assert_eq!(info.pays_fee, Pays::Yes);
+        assert_eq!(Balances::free_balance(&CallRBAC::role_account(0)), 5);
+        assert_eq!(Balances::free_balance(&2), 13);
+    });
+}
+
+#[test]
+fn free_policy_burns_fee_from_role_account() {
+    new_test_ext().execute_with(|| {
+        setup_executer(3);
+        assert_ok!(CallRBAC::fund_role(RawOrigin::Signed(1).into(), 0, 5));
+        assert_ok!(CallRBAC::set_fee_policy(
+            RawOrigin::Root.into(),
+            0,
+            FeePolicy::Free
+        ));
+        let info =
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 3)))
+                .unwrap();
+        assert_eq!(info.pays_fee, Pays::No);
+        assert_eq!(Balances::free_balance(&CallRBAC::role_account(0)), 4);
+        assert_eq!(Balances::free_balance(&2), 13);
+    });
+}
+
+#[test]
+fn refund_policy_reimburses_executer_from_role_account() {
+    new_test_ext().execute_with(|| {
+        setup_executer(3);
+        assert_ok!(CallRBAC::fund_role(RawOrigin::Signed(1).into(), 0, 5));
+        assert_ok!(CallRBAC::set_fee_policy(
+            RawOrigin::Root.into(),
+            0,
+            FeePolicy::Refund
+        ));
+        let info =
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 3)))
+                .unwrap();
+        assert_eq!(info.pays_fee, Pays::Yes);
+        assert_eq!(Balances::free_balance(&CallRBAC::role_account(0)), 4);
+        assert_eq!(Balances::free_balance(&2), 14);
+    });
+}
+
+#[test]
+fn execute_call_fails_if_role_account_cannot_pay() {
+    new_test_ext().execute_with(|| {
+        setup_executer(3);
+        assert_ok!(CallRBAC::set_fee_policy(
+            RawOrigin::Root.into(),
+            0,
+            FeePolicy::Refund
+        ));
+        assert_noop!(
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 3))),
+            Error::<Test>::InsufficientRoleFunds
+        );
+        assert_ok!(CallRBAC::fund_role(RawOrigin::Signed(1).into(), 0, 1));
+        // role account must stay alive after paying the fee
+        assert_noop!(
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 3))),
+            Error::<Test>::InsufficientRoleFunds
+        );
+    });
+}
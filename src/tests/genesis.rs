@@ -0,0 +1,82 @@
+//! Call-RBAC genesis config unit tests.
+use super::*;
+use frame_system::RawOrigin;
+
+fn encoded_transfer(dest: u64, value: u64) -> Vec<u8> {
+    CallAndOrigin::<Test> {
+        call: call_transfer(dest, value),
+        origin: RawOrigin::Signed(1).into(),
+    }
+    .encode()
+}
+
+#[test]
+fn genesis_config_sets_roles_and_calls() {
+    new_test_ext_with(call_rbac::GenesisConfig {
+        owners: vec![OWNER],
+        roles: vec![(0, 1, Role::Admin), (0, 2, Role::Executer)],
+        calls: vec![(0, vec![encoded_transfer(2, 3), encoded_transfer(2, 4)])],
+        ..Default::default()
+    })
+    .execute_with(|| {
+        assert_eq!(CallRBAC::role_info(0).map(|info| info.owner), Some(OWNER));
+        assert!(CallRBAC::role_info(1).is_none());
+        assert_eq!(CallRBAC::role(0, &1), Some(Role::Admin));
+        assert_eq!(CallRBAC::role(0, &2), Some(Role::Executer));
+        assert!(Permissions::<Test>::get(1, 0).is_none());
+        assert!(Permissions::<Test>::get(2, 0).is_some());
+        assert_eq!(PermissionCount::<Test>::get(2), 1);
+        assert_eq!(CallCount::<Test>::get(0), 2);
+        assert_eq!(
+            CallOrigins::<Test>::get(0, call_hash(call_transfer(2, 3))),
+            Some(RawOrigin::Signed(1).into())
+        );
+        assert_eq!(CallRBAC::get_allowed_calls(&2).len(), 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "genesis roles are unique and within MaxRolesPerAccount")]
+fn genesis_config_rejects_duplicate_grants() {
+    new_test_ext_with(call_rbac::GenesisConfig {
+        owners: vec![OWNER],
+        roles: vec![(0, 1, Role::Admin), (0, 1, Role::Executer)],
+        calls: vec![],
+        ..Default::default()
+    });
+}
+
+#[test]
+#[should_panic(expected = "genesis calls are within MaxCalls")]
+fn genesis_config_enforces_max_calls() {
+    new_test_ext_with(call_rbac::GenesisConfig {
+        owners: vec![OWNER],
+        roles: vec![],
+        calls: vec![(0, (0..11).map(|value| encoded_transfer(2, value)).collect())],
+        ..Default::default()
+    });
+}
+
+#[test]
+#[should_panic(expected = "genesis calls are set once per id")]
+fn genesis_config_rejects_duplicate_ids() {
+    new_test_ext_with(call_rbac::GenesisConfig {
+        owners: vec![OWNER],
+        roles: vec![],
+        calls: vec![
+            (0, vec![encoded_transfer(2, 3)]),
+            (0, vec![encoded_transfer(2, 4)]),
+        ],
+        ..Default::default()
+    });
+}
+
+#[test]
+#[should_panic(expected = "genesis roles are for created ids")]
+fn genesis_config_rejects_roles_for_unknown_ids() {
+    new_test_ext_with(call_rbac::GenesisConfig {
+        owners: vec![OWNER],
+        roles: vec![(1, 1, Role::Admin)],
+        ..Default::default()
+    });
+}
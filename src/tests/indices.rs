@@ -0,0 +1,98 @@
+//! Call-RBAC set_call_indices unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, bounded_vec};
+use frame_system::RawOrigin;
+
+fn call_transfer_keep_alive(dest: u64, value: u64) -> RuntimeCall {
+    RuntimeCall::Balances(BalancesCall::transfer_keep_alive { dest, value })
+}
+
+#[test]
+fn set_call_indices_only_for_super_user_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::set_call_indices(RawOrigin::Signed(1).into(), 0, bounded_vec![]),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(CallRBAC::set_call_indices(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![]
+        ));
+        System::assert_last_event(Event::<Test>::CallIndicesUpdated(0).into());
+    });
+}
+
+#[test]
+fn call_index_permits_any_arguments() {
+    new_test_ext().execute_with(|| {
+        let (pallet_index, call_index) = CallRBAC::call_index(&call_transfer(2, 3)).unwrap();
+        assert_ok!(CallRBAC::set_call_indices(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: CallIndex::Call(pallet_index, call_index),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(2, 3))
+        ));
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(3, 4))
+        ));
+        assert_noop!(
+            CallRBAC::execute_call(
+                RawOrigin::Signed(2).into(),
+                Box::new(call_transfer_keep_alive(2, 1))
+            ),
+            Error::<Test>::CallNotPermitted
+        );
+        assert_eq!(Balances::free_balance(&1), 3);
+    });
+}
+
+#[test]
+fn pallet_index_permits_any_call_to_pallet() {
+    new_test_ext().execute_with(|| {
+        let (pallet_index, _) = CallRBAC::call_index(&call_transfer(2, 3)).unwrap();
+        assert_ok!(CallRBAC::set_call_indices(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: CallIndex::Pallet(pallet_index),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(2, 3))
+        ));
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer_keep_alive(2, 1))
+        ));
+        assert_noop!(
+            CallRBAC::execute_call(
+                RawOrigin::Signed(2).into(),
+                Box::new(frame_system::Call::remark { remark: vec![] }.into())
+            ),
+            Error::<Test>::CallNotPermitted
+        );
+        assert_eq!(Balances::free_balance(&1), 6);
+    });
+}
@@ -0,0 +1,82 @@
+//! Call-RBAC instance unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, bounded_vec, instances::Instance1};
+use frame_system::RawOrigin;
+
+#[test]
+fn instances_have_separate_super_users_and_roles() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            TradingRBAC::grant_access(RawOrigin::Root.into(), 0, 1, Role::Admin),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(TradingRBAC::grant_access(
+            RuntimeOrigin::signed(8),
+            0,
+            1,
+            Role::Admin
+        ));
+        System::assert_last_event(
+            Event::<Test, Instance1>::AccessGranted(0, 1, Role::Admin).into(),
+        );
+        assert_eq!(TradingRBAC::role(0, &1), Some(Role::Admin));
+        assert_eq!(CallRBAC::role(0, &1), None);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Executer
+        ));
+        assert_eq!(TradingRBAC::role(0, &1), Some(Role::Admin));
+        assert_eq!(CallRBAC::role(0, &1), Some(Role::Executer));
+        assert_ne!(TradingRBAC::role_account(0), CallRBAC::role_account(0));
+    });
+}
+
+#[test]
+fn instances_have_separate_calls_and_limits() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(TradingRBAC::add_calls(
+            RuntimeOrigin::signed(8),
+            0,
+            bounded_vec![
+                CallOrigin {
+                    call: call_transfer(2, 3),
+                    origin: RawOrigin::Signed(1).into(),
+                },
+                CallOrigin {
+                    call: call_transfer(2, 4),
+                    origin: RawOrigin::Signed(1).into(),
+                }
+            ]
+        ));
+        assert_noop!(
+            TradingRBAC::add_calls(
+                RuntimeOrigin::signed(8),
+                0,
+                bounded_vec![CallOrigin {
+                    call: call_transfer(2, 5),
+                    origin: RawOrigin::Signed(1).into(),
+                }]
+            ),
+            Error::<Test, Instance1>::TooManyCalls
+        );
+        assert_eq!(CallCount::<Test, Instance1>::get(0), 2);
+        assert_eq!(CallCount::<Test>::get(0), 0);
+
+        assert_ok!(TradingRBAC::grant_access(
+            RuntimeOrigin::signed(8),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_noop!(
+            CallRBAC::execute_call(RuntimeOrigin::signed(2), Box::new(call_transfer(2, 3))),
+            Error::<Test>::CallNotPermitted
+        );
+        assert_ok!(TradingRBAC::execute_call(
+            RuntimeOrigin::signed(2),
+            Box::new(call_transfer(2, 3))
+        ));
+    });
+}
@@ -0,0 +1,179 @@
+//! Call-RBAC create_role and destroy_role unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, bounded_vec, traits::Hooks, weights::Weight};
+use frame_system::RawOrigin;
+
+#[test]
+fn create_role_assigns_next_id() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::create_role(RawOrigin::Signed(1).into(), 1, bounded_vec![]),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(CallRBAC::create_role(
+            RawOrigin::Root.into(),
+            1,
+            bounded_vec![1, 2]
+        ));
+        System::assert_last_event(Event::<Test>::RoleCreated(8, 1).into());
+        assert_ok!(CallRBAC::create_role(
+            RawOrigin::Root.into(),
+            2,
+            bounded_vec![]
+        ));
+        System::assert_last_event(Event::<Test>::RoleCreated(9, 2).into());
+        assert_eq!(
+            CallRBAC::role_info(8),
+            Some(RoleInfo {
+                owner: 1,
+                metadata: bounded_vec![1, 2],
+                destroying: false,
+            })
+        );
+        assert_eq!(NextRoleId::<Test>::get(), 10);
+    });
+}
+
+#[test]
+fn unknown_id_is_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::grant_access(RawOrigin::Root.into(), 42, 1, Role::Admin),
+            Error::<Test>::RoleDNE
+        );
+        assert_noop!(
+            CallRBAC::set_calls(
+                RawOrigin::Root.into(),
+                42,
+                bounded_vec![CallOrigin {
+                    call: call_transfer(2, 3),
+                    origin: RawOrigin::Signed(1).into(),
+                }]
+            ),
+            Error::<Test>::RoleDNE
+        );
+        assert_noop!(
+            CallRBAC::fund_role(RawOrigin::Signed(1).into(), 42, 5),
+            Error::<Test>::RoleDNE
+        );
+        assert_noop!(
+            CallRBAC::set_fee_policy(RawOrigin::Root.into(), 42, FeePolicy::Free),
+            Error::<Test>::RoleDNE
+        );
+        assert_noop!(
+            CallRBAC::reset_usage(RawOrigin::Root.into(), 42, QuotaTarget::Executer(1)),
+            Error::<Test>::RoleDNE
+        );
+    });
+}
+
+#[test]
+fn unknown_id_is_rejected_when_announced_and_applied() {
+    new_test_ext().execute_with(|| {
+        ChangeDelay::set(1);
+        assert_noop!(
+            CallRBAC::grant_access(RawOrigin::Root.into(), 42, 1, Role::Admin),
+            Error::<Test>::RoleDNE
+        );
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        assert_ok!(CallRBAC::destroy_role(RawOrigin::Root.into(), 0, 10));
+        System::set_block_number(2);
+        CallRBAC::on_idle(2, Weight::MAX);
+        System::assert_last_event(
+            Event::<Test>::ChangeApplied(0, Err(Error::<Test>::RoleDNE.into())).into(),
+        );
+        assert!(CallRBAC::role(0, &1).is_none());
+    });
+}
+
+#[test]
+fn destroy_role_only_by_owner_or_super_user() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::destroy_role(RawOrigin::Signed(1).into(), 0, 10),
+            Error::<Test>::CallerNotOwner
+        );
+        assert_noop!(
+            CallRBAC::destroy_role(RawOrigin::Root.into(), 42, 10),
+            Error::<Test>::RoleDNE
+        );
+        assert_ok!(CallRBAC::destroy_role(
+            RawOrigin::Signed(OWNER).into(),
+            0,
+            10
+        ));
+        System::assert_last_event(Event::<Test>::RoleDestroyed(0).into());
+        assert_ok!(CallRBAC::destroy_role(RawOrigin::Root.into(), 1, 10));
+        System::assert_last_event(Event::<Test>::RoleDestroyed(1).into());
+    });
+}
+
+#[test]
+fn destroy_role_removes_storage_over_several_calls() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 3),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        for who in 2..5 {
+            assert_ok!(CallRBAC::grant_access(
+                RawOrigin::Root.into(),
+                0,
+                who,
+                Role::Executer
+            ));
+        }
+        assert_ok!(CallRBAC::fund_role(RawOrigin::Signed(1).into(), 0, 5));
+
+        assert_ok!(CallRBAC::destroy_role(
+            RawOrigin::Signed(OWNER).into(),
+            0,
+            2
+        ));
+        assert_eq!(Roles::<Test>::iter_prefix(0).count(), 1);
+        assert!(CallRBAC::role_info(0).unwrap().destroying);
+        assert!(CallRBAC::is_paused(0));
+        assert_noop!(
+            CallRBAC::grant_access(RawOrigin::Root.into(), 0, 5, Role::Executer),
+            Error::<Test>::RoleDestroying
+        );
+        assert_noop!(
+            CallRBAC::unpause_role(RawOrigin::Root.into(), 0),
+            Error::<Test>::RoleDestroying
+        );
+        assert_ok!(CallRBAC::do_try_state());
+
+        assert_ok!(CallRBAC::destroy_role(
+            RawOrigin::Signed(OWNER).into(),
+            0,
+            2
+        ));
+        assert!(CallRBAC::role_info(0).is_some());
+        assert_ok!(CallRBAC::destroy_role(
+            RawOrigin::Signed(OWNER).into(),
+            0,
+            2
+        ));
+        System::assert_last_event(Event::<Test>::RoleDestroyed(0).into());
+        assert!(CallRBAC::role_info(0).is_none());
+        assert!(!CallRBAC::is_paused(0));
+        assert_eq!(Roles::<Test>::iter_prefix(0).count(), 0);
+        assert_eq!(Permissions::<Test>::iter().count(), 0);
+        assert_eq!(PermissionCount::<Test>::iter().count(), 0);
+        assert_eq!(CallOrigins::<Test>::iter_prefix(0).count(), 0);
+        assert!(Calls::<Test>::get(call_hash(call_transfer(2, 3))).is_none());
+        assert_eq!(CallCount::<Test>::get(0), 0);
+        assert_eq!(Balances::free_balance(&CallRBAC::role_account(0)), 0);
+        assert_eq!(Balances::free_balance(&OWNER), 5);
+        assert_ok!(CallRBAC::do_try_state());
+    });
+}
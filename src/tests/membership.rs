@@ -0,0 +1,82 @@
+//! Call-RBAC membership adapter unit tests.
+use super::*;
+use crate::{
+    membership::RoleMembers,
+    origins::{AdminRole, ExecuterRole},
+};
+use frame_support::{
+    assert_ok,
+    traits::{ChangeMembers, ConstU64, Contains, InitializeMembers, SortedMembers},
+};
+use frame_system::RawOrigin;
+
+type Executers = RoleMembers<Test, ConstU64<0>, ExecuterRole>;
+type Admins = RoleMembers<Test, ConstU64<0>, AdminRole>;
+
+#[test]
+fn role_members_reads_roles() {
+    new_test_ext().execute_with(|| {
+        for (who, role) in [(3, Role::Executer), (1, Role::Admin), (2, Role::Executer)] {
+            assert_ok!(CallRBAC::grant_access(RawOrigin::Root.into(), 0, who, role));
+        }
+        assert_eq!(Executers::sorted_members(), vec![2, 3]);
+        assert_eq!(Admins::sorted_members(), vec![1]);
+        assert!(<Executers as Contains<u64>>::contains(&2));
+        assert!(!<Executers as Contains<u64>>::contains(&1));
+        assert_eq!(Executers::count(), 2);
+    });
+}
+
+#[test]
+fn role_members_changes_roles() {
+    new_test_ext().execute_with(|| {
+        Executers::initialize_members(&[1, 2]);
+        assert_eq!(CallRBAC::role(0, &1), Some(Role::Executer));
+        assert_eq!(PermissionCount::<Test>::get(2), 1);
+        Executers::change_members_sorted(&[3], &[1], &[2, 3]);
+        System::assert_last_event(Event::<Test>::AccessGranted(0, 3, Role::Executer).into());
+        assert_eq!(Executers::sorted_members(), vec![2, 3]);
+        assert!(Permissions::<Test>::get(1, 0).is_none());
+        // roles other than R are not revoked
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            4,
+            Role::Admin
+        ));
+        Executers::change_members_sorted(&[], &[4], &[2, 3]);
+        assert_eq!(CallRBAC::role(0, &4), Some(Role::Admin));
+    });
+}
+
+#[test]
+fn failed_grant_keeps_expired_grant() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::grant_access_until(
+            RawOrigin::Root.into(),
+            0,
+            4,
+            Role::Admin,
+            2
+        ));
+        for id in 1..4 {
+            assert_ok!(CallRBAC::grant_access(
+                RawOrigin::Root.into(),
+                id,
+                4,
+                Role::Executer
+            ));
+        }
+        System::set_block_number(2);
+        Executers::initialize_members(&[4]);
+        assert_eq!(
+            Roles::<Test>::get(0, 4),
+            Some(Grant {
+                role: Role::Admin,
+                expiry: Some(2),
+            })
+        );
+        assert!(ExpiryQueue::<Test>::contains_key(2, (0, 4)));
+        assert_eq!(PermissionCount::<Test>::get(4), 3);
+    });
+}
@@ -0,0 +1,134 @@
+//! Call-RBAC role metadata unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, bounded_vec};
+use frame_system::RawOrigin;
+
+#[test]
+fn set_role_metadata_only_by_owner_or_super_user() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::set_role_metadata(
+                RawOrigin::Signed(1).into(),
+                0,
+                bounded_vec![],
+                bounded_vec![],
+                bounded_vec![]
+            ),
+            Error::<Test>::CallerNotOwner
+        );
+        assert_noop!(
+            CallRBAC::set_role_metadata(
+                RawOrigin::Root.into(),
+                42,
+                bounded_vec![],
+                bounded_vec![],
+                bounded_vec![]
+            ),
+            Error::<Test>::RoleDNE
+        );
+        assert_ok!(CallRBAC::set_role_metadata(
+            RawOrigin::Signed(OWNER).into(),
+            0,
+            bounded_vec![b't', b'r'],
+            bounded_vec![b'd'],
+            bounded_vec![bounded_vec![b'x']]
+        ));
+        System::assert_last_event(Event::<Test>::RoleMetadataSet(0).into());
+        assert_ok!(CallRBAC::set_role_metadata(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![b'o', b'p', b's'],
+            bounded_vec![],
+            bounded_vec![bounded_vec![b'a'], bounded_vec![b'b']]
+        ));
+        assert_eq!(
+            Metadata::<Test>::get(0),
+            Some(RoleMetadata {
+                name: bounded_vec![b'o', b'p', b's'],
+                description: bounded_vec![],
+                tags: bounded_vec![bounded_vec![b'a'], bounded_vec![b'b']],
+            })
+        );
+    });
+}
+
+#[test]
+fn role_details_include_metadata_members_and_calls() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(CallRBAC::role_details(42), None);
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 3),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_eq!(
+            CallRBAC::role_details(0),
+            Some(RoleDetails {
+                owner: OWNER,
+                opaque_metadata: vec![],
+                metadata: None,
+                members: vec![(2, Role::Executer)],
+                calls: vec![(call_transfer(2, 3), RawOrigin::Signed(1).into())],
+            })
+        );
+        assert_ok!(CallRBAC::set_role_metadata(
+            RawOrigin::Signed(OWNER).into(),
+            0,
+            bounded_vec![b't'],
+            bounded_vec![b'd'],
+            bounded_vec![bounded_vec![b'x']]
+        ));
+        assert_eq!(
+            CallRBAC::role_details(0).unwrap().metadata,
+            Some(RoleMetadata {
+                name: vec![b't'],
+                description: vec![b'd'],
+                tags: vec![vec![b'x']],
+            })
+        );
+        assert_ok!(CallRBAC::create_role(
+            RawOrigin::Root.into(),
+            OWNER,
+            bounded_vec![1, 2]
+        ));
+        assert_eq!(
+            CallRBAC::role_details(8).unwrap().opaque_metadata,
+            vec![1, 2]
+        );
+    });
+}
+
+#[test]
+fn destroy_role_removes_metadata() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_role_metadata(
+            RawOrigin::Signed(OWNER).into(),
+            0,
+            bounded_vec![b't'],
+            bounded_vec![],
+            bounded_vec![]
+        ));
+        assert_ok!(CallRBAC::destroy_role(RawOrigin::Root.into(), 0, 1));
+        assert_noop!(
+            CallRBAC::set_role_metadata(
+                RawOrigin::Signed(OWNER).into(),
+                0,
+                bounded_vec![],
+                bounded_vec![],
+                bounded_vec![]
+            ),
+            Error::<Test>::RoleDNE
+        );
+        assert!(Metadata::<Test>::get(0).is_none());
+        assert_eq!(CallRBAC::role_details(0), None);
+    });
+}
@@ -0,0 +1,223 @@
+//! Call-RBAC storage migration unit tests.
+use super::*;
+use crate::migrations::{
+    v1::{v0, MigrateToV1},
+    v2::{self, MigrateToV2},
+    v3::MigrateToV3,
+};
+use frame_support::{
+    assert_ok, bounded_vec,
+    storage::{storage_prefix, unhashed},
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    Blake2_128Concat, StorageHasher,
+};
+use frame_system::RawOrigin;
+
+/// Insert origin for id and call at the `CallOrigins` key of the baseline layout
+fn insert_baseline_call_origin(id: RoleId, call: &RuntimeCall, origin: OriginCaller) {
+    let mut key = storage_prefix(b"CallRBAC", b"CallOrigins").to_vec();
+    key.extend(Blake2_128Concat::hash(&id.encode()));
+    key.extend(Blake2_128Concat::hash(&call.encode()));
+    unhashed::put(&key, &origin);
+}
+
+#[test]
+fn migrate_to_v1_keys_call_origins_by_hash() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<CallRBAC>();
+        let origin: OriginCaller = RawOrigin::Signed(1).into();
+        insert_baseline_call_origin(0, &call_transfer(2, 3), origin.clone());
+        insert_baseline_call_origin(1, &call_transfer(2, 3), origin.clone());
+        assert_eq!(
+            v0::CallOrigins::<Test, ()>::get(0, call_transfer(2, 3)),
+            Some(origin.clone())
+        );
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        let hash = call_hash(call_transfer(2, 3));
+        let encoded = CallRBAC::encode_call(&call_transfer(2, 3)).unwrap();
+        assert_eq!(CallRBAC::on_chain_storage_version(), 1);
+        assert_eq!(CallOrigins::<Test>::get(0, hash), Some(origin.clone()));
+        assert_eq!(CallOrigins::<Test>::get(1, hash), Some(origin));
+        assert_eq!(Calls::<Test>::get(hash), Some((encoded, 2)));
+        assert_eq!(CallOrigins::<Test>::iter().count(), 2);
+        assert_eq!(CallCount::<Test>::get(0), 1);
+        assert_eq!(CallRBAC::stored_call(hash), Some(call_transfer(2, 3)));
+    });
+}
+
+#[test]
+fn migrate_to_v1_drops_calls_longer_than_max_call_len() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<CallRBAC>();
+        let long: RuntimeCall = frame_system::Call::remark {
+            remark: vec![0; 100],
+        }
+        .into();
+        insert_baseline_call_origin(0, &long, RawOrigin::Signed(1).into());
+        insert_baseline_call_origin(0, &call_transfer(2, 3), RawOrigin::Signed(1).into());
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(CallOrigins::<Test>::iter().count(), 1);
+        assert_eq!(CallCount::<Test>::get(0), 1);
+        assert!(Calls::<Test>::get(call_hash(long)).is_none());
+    });
+}
+
+#[test]
+fn migrate_to_v1_skips_if_already_migrated() {
+    new_test_ext().execute_with(|| {
+        insert_baseline_call_origin(0, &call_transfer(2, 3), RawOrigin::Signed(1).into());
+        StorageVersion::new(1).put::<CallRBAC>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert!(Calls::<Test>::get(call_hash(call_transfer(2, 3))).is_none());
+    });
+}
+
+#[test]
+fn migrate_to_v2_wraps_roles_in_grants() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<CallRBAC>();
+        v2::v1::Roles::<Test, ()>::insert(0, 1, Role::Admin);
+        v2::v1::Roles::<Test, ()>::insert(0, 2, Role::Executer);
+
+        MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(CallRBAC::on_chain_storage_version(), 2);
+        assert_eq!(
+            Roles::<Test>::get(0, 1),
+            Some(Grant {
+                role: Role::Admin,
+                expiry: None
+            })
+        );
+        assert_eq!(CallRBAC::role(0, &2), Some(Role::Executer));
+    });
+}
+
+#[test]
+fn migrations_run_in_order_from_v0() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<CallRBAC>();
+        insert_baseline_call_origin(0, &call_transfer(2, 3), RawOrigin::Signed(1).into());
+        v2::v1::Roles::<Test, ()>::insert(0, 1, Role::Admin);
+        for id in [0, 1] {
+            v2::v1::Roles::<Test, ()>::insert(id, 2, Role::Executer);
+            Permissions::<Test>::insert(2, id, ());
+        }
+
+        crate::migrations::Migrations::<Test>::on_runtime_upgrade();
+
+        assert_eq!(CallRBAC::on_chain_storage_version(), 3);
+        assert_eq!(CallCount::<Test>::get(0), 1);
+        assert_eq!(CallRBAC::role(0, &1), Some(Role::Admin));
+        assert_eq!(PermissionCount::<Test>::get(2), 2);
+        assert_eq!(PermissionCount::<Test>::get(1), 0);
+        assert_ok!(CallRBAC::do_try_state());
+    });
+}
+
+#[test]
+fn try_state_holds_after_role_and_call_changes() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 3),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            1,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::do_try_state());
+        assert_ok!(CallRBAC::revoke_access(RawOrigin::Root.into(), 0, 2));
+        assert_ok!(CallRBAC::do_try_state());
+    });
+}
+
+#[test]
+fn try_state_detects_inconsistent_permissions() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            2,
+            Role::Executer
+        ));
+        Roles::<Test>::remove(0, 2);
+        assert!(CallRBAC::do_try_state().is_err());
+        Roles::<Test>::insert(
+            0,
+            2,
+            Grant {
+                role: Role::Admin,
+                expiry: None,
+            },
+        );
+        assert!(CallRBAC::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn try_state_detects_inconsistent_call_count() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            0,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 3),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        CallCount::<Test>::insert(0, 2);
+        assert!(CallRBAC::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn migrate_to_v3_registers_ids_in_use() {
+    new_test_ext_with(Default::default()).execute_with(|| {
+        StorageVersion::new(2).put::<CallRBAC>();
+        Roles::<Test>::insert(
+            3,
+            1,
+            Grant {
+                role: Role::Admin,
+                expiry: None,
+            },
+        );
+        FeePolicies::<Test>::insert(5, FeePolicy::Free);
+
+        MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(CallRBAC::on_chain_storage_version(), 3);
+        assert_eq!(
+            CallRBAC::role_info(3).map(|info| info.owner),
+            Some(CallRBAC::role_account(3))
+        );
+        assert!(CallRBAC::role_info(5).is_some());
+        assert!(CallRBAC::role_info(4).is_none());
+        assert_eq!(NextRoleId::<Test>::get(), 6);
+    });
+}
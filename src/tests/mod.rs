@@ -1,17 +1,41 @@
 //! Call-RBAC unit test environment.
 use super::*;
 use crate as call_rbac;
-use frame_support::traits::{ConstU16, ConstU32, ConstU64};
-use frame_system::EnsureRoot;
+use frame_support::{
+    assert_ok, bounded_vec,
+    instances::Instance1,
+    ord_parameter_types, parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64, Hooks},
+    weights::{FixedFee, Weight},
+    PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSignedBy, RawOrigin};
 use pallet_balances::Call as BalancesCall;
 use sp_core::H256;
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup},
+    traits::{BlakeTwo256, Hash, IdentityLookup},
     BuildStorage,
 };
 
 mod access;
+mod approvals;
+mod audit;
 mod calls;
+mod changes;
+mod constraints;
+mod deposits;
+mod expiry;
+mod fees;
+mod genesis;
+mod indices;
+mod instances;
+mod lifecycle;
+mod membership;
+mod metadata;
+mod migrations;
+mod origins;
+mod pause;
+mod quotas;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -20,7 +44,8 @@ frame_support::construct_runtime!(
     {
         Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-        CallRBAC: call_rbac::{Pallet, Call, Storage, Event<T>},
+        CallRBAC: call_rbac::{Pallet, Call, Config<T>, Storage, Event<T>, HoldReason},
+        TradingRBAC: call_rbac::<Instance1>::{Pallet, Call, Config<T>, Storage, Event<T>, HoldReason},
     }
 );
 
@@ -43,6 +68,41 @@ pub enum TestId {
     Baz,
 }
 
+/// Constraints on `transfer_allow_death` arguments
+#[derive(Encode, Decode, Clone, Eq, PartialEq, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub enum TransferConstraint {
+    Any,
+    ValueAtMost(u64),
+    DestIn(BoundedVec<u64, ConstU32<4>>),
+}
+
+impl MatchCall<RuntimeCall> for TransferConstraint {
+    fn matches(&self, call: &RuntimeCall) -> bool {
+        let RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest, value }) = call else {
+            return false;
+        };
+        match self {
+            TransferConstraint::Any => true,
+            TransferConstraint::ValueAtMost(max) => value <= max,
+            TransferConstraint::DestIn(dests) => dests.contains(dest),
+        }
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl call_rbac::BenchmarkHelper<TransferConstraint, RuntimeCall> for BenchmarkHelper {
+    fn call_constraint(i: u32) -> TransferConstraint {
+        TransferConstraint::ValueAtMost(i as u64)
+    }
+
+    fn matching_call_constraint() -> (TransferConstraint, RuntimeCall) {
+        (TransferConstraint::Any, call_transfer(2, u64::MAX))
+    }
+}
+
 impl pallet_balances::Config for Test {
     type MaxLocks = ();
     type MaxReserves = ();
@@ -53,10 +113,10 @@ impl pallet_balances::Config for Test {
     type ExistentialDeposit = ConstU64<1>;
     type AccountStore = System;
     type WeightInfo = ();
-    type RuntimeHoldReason = TestId;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type FreezeIdentifier = TestId;
     type MaxFreezes = (); //ConstU32<2>
-    type MaxHolds = ();
+    type MaxHolds = ConstU32<4>;
 }
 
 impl frame_system::Config for Test {
@@ -85,18 +145,98 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+ord_parameter_types! {
+    pub const Guardian: u64 = 9;
+    pub const Fund: u64 = 8;
+}
+
+parameter_types! {
+    pub const CallRbacPalletId: PalletId = PalletId(*b"py/clrbc");
+    pub const TradingRbacPalletId: PalletId = PalletId(*b"py/trrbc");
+    pub static ChangeDelay: u64 = 0;
+    pub static GrantDeposit: u64 = 0;
+    pub static CallDepositPerByte: u64 = 0;
+    pub const DepositAccount: u64 = 7;
+}
+
 impl call_rbac::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type SuperUser = EnsureRoot<u64>;
+    type PauseOrigin = EnsureSignedBy<Guardian, u64>;
     type ValidateCall = CallRBAC;
     type RuntimeCall = RuntimeCall;
     type RuntimeOrigin = RuntimeOrigin;
     type PalletsOrigin = OriginCaller;
     type MaxCalls = ConstU32<10>;
+    type MaxRolesPerAccount = ConstU32<3>;
+    type MaxCallLen = ConstU32<64>;
+    type CallConstraint = TransferConstraint;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type GrantDeposit = GrantDeposit;
+    type CallDepositPerByte = CallDepositPerByte;
+    type DepositPayer = DepositAccount;
+    type WeightToFee = FixedFee<1, u64>;
+    type ApprovalThreshold = ConstU32<2>;
+    type ProposalLifetime = ConstU64<10>;
+    type ChangeDelay = ChangeDelay;
+    type AuditLogDepth = ConstU32<4>;
+    type MaxMetadataLen = ConstU32<16>;
+    type MaxNameLen = ConstU32<16>;
+    type MaxDescriptionLen = ConstU32<64>;
+    type MaxTags = ConstU32<2>;
+    type MaxTagLen = ConstU32<8>;
+    type PalletId = CallRbacPalletId;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
 }
 
+impl call_rbac::Config<Instance1> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type SuperUser = EnsureSignedBy<Fund, u64>;
+    type PauseOrigin = EnsureSignedBy<Fund, u64>;
+    type ValidateCall = TradingRBAC;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type MaxCalls = ConstU32<2>;
+    type MaxRolesPerAccount = ConstU32<3>;
+    type MaxCallLen = ConstU32<64>;
+    type CallConstraint = TransferConstraint;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type GrantDeposit = ConstU64<0>;
+    type CallDepositPerByte = ConstU64<0>;
+    type DepositPayer = DepositAccount;
+    type WeightToFee = FixedFee<1, u64>;
+    type ApprovalThreshold = ConstU32<2>;
+    type ProposalLifetime = ConstU64<10>;
+    type ChangeDelay = ConstU64<0>;
+    type AuditLogDepth = ConstU32<4>;
+    type MaxMetadataLen = ConstU32<16>;
+    type MaxNameLen = ConstU32<16>;
+    type MaxDescriptionLen = ConstU32<64>;
+    type MaxTags = ConstU32<2>;
+    type MaxTagLen = ConstU32<8>;
+    type PalletId = TradingRbacPalletId;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BenchmarkHelper;
+}
+
+/// Owner of the ids created at genesis
+pub const OWNER: u64 = 10;
+
+/// Create ids 0 to 7 at genesis
 pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_with(call_rbac::GenesisConfig {
+        owners: vec![OWNER; 8],
+        ..Default::default()
+    })
+}
+
+pub fn new_test_ext_with(call_rbac: call_rbac::GenesisConfig<Test>) -> sp_io::TestExternalities {
     let mut storage = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
@@ -105,6 +245,13 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     }
     .assimilate_storage(&mut storage)
     .unwrap();
+    call_rbac.assimilate_storage(&mut storage).unwrap();
+    call_rbac::GenesisConfig::<Test, Instance1> {
+        owners: vec![Fund::get()],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
     let mut ext: sp_io::TestExternalities = storage.into();
     ext.execute_with(|| System::set_block_number(1));
     ext.into()
@@ -113,3 +260,32 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 pub fn call_transfer(dest: u64, value: u64) -> RuntimeCall {
     RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest, value })
 }
+
+pub fn call_hash(call: RuntimeCall) -> CallHashOf<Test> {
+    BlakeTwo256::hash_of(&call)
+}
+
+/// Permit id 0 to transfer `value` from account 1 to account 2 and grant Executer access
+/// for id 0 to account 2
+pub fn setup_executer(value: u64) {
+    assert_ok!(CallRBAC::set_calls(
+        RawOrigin::Root.into(),
+        0,
+        bounded_vec![CallOrigin {
+            call: call_transfer(2, value),
+            origin: RawOrigin::Signed(1).into(),
+        }]
+    ));
+    assert_ok!(CallRBAC::grant_access(
+        RawOrigin::Root.into(),
+        0,
+        2,
+        Role::Executer
+    ));
+}
+
+/// Set the block number and run `on_idle` with unlimited weight
+pub fn run_idle(block: u64) {
+    System::set_block_number(block);
+    CallRBAC::on_idle(block, Weight::MAX);
+}
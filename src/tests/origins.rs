@@ -0,0 +1,63 @@
+//! Call-RBAC origin unit tests.
+use super::*;
+use crate::origins::{AdminRole, EnsureRoleAdmin, EnsureRoleExecuter, EnsureRoleWithArg};
+use frame_support::{
+    assert_ok,
+    traits::{ConstU64, EnsureOrigin, EnsureOriginWithArg},
+};
+use frame_system::RawOrigin;
+
+#[test]
+fn ensure_role_checks_role_for_id() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            7,
+            1,
+            Role::Admin
+        ));
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            7,
+            2,
+            Role::Executer
+        ));
+        assert_eq!(
+            EnsureRoleAdmin::<Test, ConstU64<7>>::try_origin(RuntimeOrigin::signed(1)).ok(),
+            Some(1)
+        );
+        assert!(
+            EnsureRoleAdmin::<Test, ConstU64<7>>::try_origin(RuntimeOrigin::signed(2)).is_err()
+        );
+        assert!(
+            EnsureRoleAdmin::<Test, ConstU64<6>>::try_origin(RuntimeOrigin::signed(1)).is_err()
+        );
+        assert!(EnsureRoleAdmin::<Test, ConstU64<7>>::try_origin(RuntimeOrigin::root()).is_err());
+        assert_eq!(
+            EnsureRoleExecuter::<Test, ConstU64<7>>::try_origin(RuntimeOrigin::signed(2)).ok(),
+            Some(2)
+        );
+        assert!(
+            EnsureRoleExecuter::<Test, ConstU64<7>>::try_origin(RuntimeOrigin::signed(1)).is_err()
+        );
+    });
+}
+
+#[test]
+fn ensure_role_with_arg_checks_role_for_input_id() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            7,
+            1,
+            Role::Admin
+        ));
+        assert_eq!(
+            EnsureRoleWithArg::<Test, AdminRole>::try_origin(RuntimeOrigin::signed(1), &7).ok(),
+            Some(1)
+        );
+        assert!(
+            EnsureRoleWithArg::<Test, AdminRole>::try_origin(RuntimeOrigin::signed(1), &6).is_err()
+        );
+    });
+}
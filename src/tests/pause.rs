@@ -0,0 +1,89 @@
+//! Call-RBAC pause unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, bounded_vec};
+use frame_system::RawOrigin;
+
+#[test]
+fn pause_only_for_pause_origin_or_super_user() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CallRBAC::pause_role(RawOrigin::Signed(1).into(), 0),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(CallRBAC::pause_role(RawOrigin::Signed(9).into(), 0));
+        System::assert_last_event(Event::<Test>::RolePaused(0).into());
+        assert_ok!(CallRBAC::unpause_role(RawOrigin::Root.into(), 0));
+        System::assert_last_event(Event::<Test>::RoleUnpaused(0).into());
+        assert_noop!(
+            CallRBAC::pause_all(RawOrigin::Signed(1).into()),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(CallRBAC::pause_all(RawOrigin::Signed(9).into()));
+        System::assert_last_event(Event::<Test>::AllPaused.into());
+    });
+}
+
+#[test]
+fn paused_role_refuses_calls_but_keeps_grants() {
+    new_test_ext().execute_with(|| {
+        setup_executer(1);
+        assert_ok!(CallRBAC::set_calls(
+            RawOrigin::Root.into(),
+            1,
+            bounded_vec![CallOrigin {
+                call: call_transfer(2, 1),
+                origin: RawOrigin::Signed(1).into(),
+            }]
+        ));
+        assert_ok!(CallRBAC::pause_role(RawOrigin::Signed(9).into(), 0));
+        assert_noop!(
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 1))),
+            Error::<Test>::CallNotPermitted
+        );
+        assert!(CallRBAC::can_execute(&2, &call_transfer(2, 1)).is_none());
+        assert_eq!(CallRBAC::role(0, &2), Some(Role::Executer));
+        // other roles of the account are not paused
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            1,
+            2,
+            Role::Executer
+        ));
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(2, 1))
+        ));
+        assert_ok!(CallRBAC::unpause_role(RawOrigin::Signed(9).into(), 0));
+        assert_ok!(CallRBAC::execute_call_with_role(
+            RawOrigin::Signed(2).into(),
+            0,
+            Box::new(call_transfer(2, 1))
+        ));
+    });
+}
+
+#[test]
+fn pause_all_refuses_calls_for_all_roles() {
+    new_test_ext().execute_with(|| {
+        setup_executer(1);
+        assert_ok!(CallRBAC::pause_all(RawOrigin::Root.into()));
+        assert_noop!(
+            CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 1))),
+            Error::<Test>::CallNotPermitted
+        );
+        assert_noop!(
+            CallRBAC::execute_call_with_role(
+                RawOrigin::Signed(2).into(),
+                0,
+                Box::new(call_transfer(2, 1))
+            ),
+            Error::<Test>::CallNotPermitted
+        );
+        assert_ok!(CallRBAC::unpause_all(RawOrigin::Root.into()));
+        System::assert_last_event(Event::<Test>::AllUnpaused.into());
+        assert_ok!(CallRBAC::execute_call(
+            RawOrigin::Signed(2).into(),
+            Box::new(call_transfer(2, 1))
+        ));
+    });
+}
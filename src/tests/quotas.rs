@@ -0,0 +1,114 @@
+//! Call-RBAC execution quota unit tests.
+use super::*;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
+use frame_system::RawOrigin;
+
+fn execute() -> DispatchResultWithPostInfo {
+    CallRBAC::execute_call(RawOrigin::Signed(2).into(), Box::new(call_transfer(2, 1)))
+}
+
+#[test]
+fn set_quota_only_for_admin_or_super_user() {
+    new_test_ext().execute_with(|| {
+        setup_executer(1);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        let quota = Quota {
+            per_period: None,
+            total: Some(1),
+        };
+        assert_noop!(
+            CallRBAC::set_quota(
+                RawOrigin::Signed(2).into(),
+                0,
+                QuotaTarget::Executer(2),
+                Some(quota)
+            ),
+            Error::<Test>::CallerNotAdmin
+        );
+        assert_ok!(CallRBAC::set_quota(
+            RawOrigin::Signed(1).into(),
+            0,
+            QuotaTarget::Executer(2),
+            Some(quota)
+        ));
+        System::assert_last_event(
+            Event::<Test>::QuotaUpdated(0, QuotaTarget::Executer(2), Some(quota)).into(),
+        );
+        assert_ok!(CallRBAC::set_quota(
+            RawOrigin::Root.into(),
+            0,
+            QuotaTarget::Executer(2),
+            None
+        ));
+        assert!(Quotas::<Test>::get(0, QuotaTarget::Executer(2)).is_none());
+    });
+}
+
+#[test]
+fn total_quota_limits_executions_until_reset() {
+    new_test_ext().execute_with(|| {
+        setup_executer(1);
+        assert_ok!(CallRBAC::grant_access(
+            RawOrigin::Root.into(),
+            0,
+            1,
+            Role::Admin
+        ));
+        assert_ok!(CallRBAC::set_quota(
+            RawOrigin::Root.into(),
+            0,
+            QuotaTarget::Executer(2),
+            Some(Quota {
+                per_period: None,
+                total: Some(2),
+            })
+        ));
+        assert_ok!(execute());
+        assert_ok!(execute());
+        assert_noop!(execute(), Error::<Test>::QuotaExceeded);
+        assert_eq!(Usages::<Test>::get(0, QuotaTarget::Executer(2)).total, 2);
+        assert_ok!(CallRBAC::reset_usage(
+            RawOrigin::Signed(1).into(),
+            0,
+            QuotaTarget::Executer(2)
+        ));
+        System::assert_last_event(Event::<Test>::UsageReset(0, QuotaTarget::Executer(2)).into());
+        assert_ok!(execute());
+    });
+}
+
+#[test]
+fn period_quota_limits_executions_per_period() {
+    new_test_ext().execute_with(|| {
+        setup_executer(1);
+        let hash = call_hash(call_transfer(2, 1));
+        assert_ok!(CallRBAC::set_quota(
+            RawOrigin::Root.into(),
+            0,
+            QuotaTarget::Call(hash),
+            Some(Quota {
+                per_period: Some((1, 10)),
+                total: None,
+            })
+        ));
+        assert_ok!(execute());
+        assert_noop!(execute(), Error::<Test>::QuotaExceeded);
+        System::set_block_number(10);
+        assert_noop!(execute(), Error::<Test>::QuotaExceeded);
+        System::set_block_number(11);
+        assert_ok!(execute());
+        assert_eq!(
+            Usages::<Test>::get(0, QuotaTarget::Call(hash)),
+            Usage {
+                period_start: 11,
+                in_period: 1,
+                total: 2,
+            }
+        );
+    });
+}
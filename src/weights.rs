@@ -0,0 +1,137 @@
+//! Weights for `pallet_call_rbac`.
+//!
+//! No measured weights are committed yet. Generate `SubstrateWeight` into this file by running
+//! the benchmarks in `benchmarking.rs` with the benchmark CLI on reference hardware, e.g.:
+//!
+//! ```text
+//! ./target/production/node benchmark pallet --chain=dev --steps=50 --repeat=20 \
+//!     --pallet=pallet_call_rbac --extrinsic=* --wasm-execution=compiled \
+//!     --heap-pages=4096 --output=./src/weights.rs \
+//!     --template=./.maintain/frame-weight-template.hbs
+//! ```
+//!
+//! Until then runtimes must provide their own `WeightInfo`. The `()` implementation returns
+//! zero weights and is only meant for tests.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::weights::Weight;
+
+/// Weight functions needed for `pallet_call_rbac`.
+pub trait WeightInfo {
+	fn grant_access() -> Weight;
+	fn revoke_access() -> Weight;
+	fn set_calls(x: u32, ) -> Weight;
+	fn set_call_constraints(x: u32, ) -> Weight;
+	fn set_call_indices(x: u32, ) -> Weight;
+	fn add_calls(x: u32, ) -> Weight;
+	fn remove_calls(x: u32, ) -> Weight;
+	fn execute_call(r: u32, ) -> Weight;
+	fn execute_call_with_role() -> Weight;
+	fn fund_role() -> Weight;
+	fn set_fee_policy() -> Weight;
+	fn grant_access_until() -> Weight;
+	fn expire_grant() -> Weight;
+	fn set_quota() -> Weight;
+	fn reset_usage() -> Weight;
+	fn set_approval_required() -> Weight;
+	fn propose_call() -> Weight;
+	fn approve_call() -> Weight;
+	fn cancel_proposal() -> Weight;
+	fn cancel_pending_change() -> Weight;
+	fn pause_role() -> Weight;
+	fn unpause_role() -> Weight;
+	fn pause_all() -> Weight;
+	fn unpause_all() -> Weight;
+	fn create_role() -> Weight;
+	fn destroy_role(n: u32, ) -> Weight;
+	fn set_role_metadata() -> Weight;
+}
+
+/// Zero weights for tests.
+impl WeightInfo for () {
+	fn grant_access() -> Weight {
+		Weight::zero()
+	}
+	fn revoke_access() -> Weight {
+		Weight::zero()
+	}
+	fn set_calls(_x: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn set_call_constraints(_x: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn set_call_indices(_x: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn add_calls(_x: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn remove_calls(_x: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn execute_call(_r: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn execute_call_with_role() -> Weight {
+		Weight::zero()
+	}
+	fn fund_role() -> Weight {
+		Weight::zero()
+	}
+	fn set_fee_policy() -> Weight {
+		Weight::zero()
+	}
+	fn grant_access_until() -> Weight {
+		Weight::zero()
+	}
+	fn expire_grant() -> Weight {
+		Weight::zero()
+	}
+	fn set_quota() -> Weight {
+		Weight::zero()
+	}
+	fn reset_usage() -> Weight {
+		Weight::zero()
+	}
+	fn set_approval_required() -> Weight {
+		Weight::zero()
+	}
+	fn propose_call() -> Weight {
+		Weight::zero()
+	}
+	fn approve_call() -> Weight {
+		Weight::zero()
+	}
+	fn cancel_proposal() -> Weight {
+		Weight::zero()
+	}
+	fn cancel_pending_change() -> Weight {
+		Weight::zero()
+	}
+	fn pause_role() -> Weight {
+		Weight::zero()
+	}
+	fn unpause_role() -> Weight {
+		Weight::zero()
+	}
+	fn pause_all() -> Weight {
+		Weight::zero()
+	}
+	fn unpause_all() -> Weight {
+		Weight::zero()
+	}
+	fn create_role() -> Weight {
+		Weight::zero()
+	}
+	fn destroy_role(_n: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn set_role_metadata() -> Weight {
+		Weight::zero()
+	}
+}